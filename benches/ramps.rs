@@ -0,0 +1,16 @@
+use colorbrewer::{get_color_ramp, Palette};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_ramps(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ramp lookup");
+    group.bench_function("get_color_ramp (Vec)", |b| {
+        b.iter(|| get_color_ramp(black_box(Palette::Spectral), black_box(9)))
+    });
+    group.bench_function("Palette::colors (static slice)", |b| {
+        b.iter(|| black_box(Palette::Spectral).colors(black_box(9)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ramps);
+criterion_main!(benches);
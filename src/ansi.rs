@@ -0,0 +1,338 @@
+//! Rendering of colors in the terminal with ANSI escape sequences, either as 24-bit
+//! colors or, for the terminals without true color support, as the nearest color of
+//! the xterm 256 color palette.
+//!
+//! ```rust
+//! use colorbrewer::ansi::{swatches, ColorMode, Painter};
+//! use colorbrewer::{Classifier, Palette};
+//!
+//! let preview = swatches(Palette::RdBu.colors(5).unwrap(), 2, ColorMode::TrueColor);
+//! assert!(preview.starts_with("\x1b[48;2;202;0;32m  "));
+//!
+//! let classifier = Classifier::new(Palette::Reds, 3, vec![0., 10., 50., 100.]).unwrap();
+//! let painter = Painter::classes(classifier).mode(ColorMode::Ansi256);
+//! for value in [4., 42., 99.] {
+//!     println!("{}", painter.paint(&format!("{:>5}", value), value));
+//! }
+//! ```
+
+use crate::classifier::DEFAULT_NO_DATA;
+use crate::colorspace::rgb_to_lab;
+use crate::{Classifier, Colormap};
+use rgb::RGB;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+/// Escape sequence resetting the colors (and other attributes) of the terminal.
+pub const RESET: &str = "\x1b[0m";
+
+/// Levels of the components of the 6×6×6 color cube of the xterm 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// How colors are written in the escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// 24-bit colors (`ESC[38;2;r;g;bm`).
+    #[default]
+    TrueColor,
+    /// Nearest color of the xterm 256 color palette (`ESC[38;5;nm`).
+    Ansi256,
+}
+
+impl ColorMode {
+    /// Guess the mode supported by the terminal the standard output is written to.
+    ///
+    /// Return `None` if the standard output is not a terminal or if the `NO_COLOR`
+    /// environment variable is set (and not empty). Otherwise, the mode is guessed from
+    /// the `COLORTERM` environment variable: `TrueColor` if it is `truecolor` or `24bit`,
+    /// `Ansi256` otherwise.
+    pub fn detect() -> Option<Self> {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if no_color || !io::stdout().is_terminal() {
+            return None;
+        }
+        match env::var("COLORTERM").as_deref() {
+            Ok("truecolor") | Ok("24bit") => Some(ColorMode::TrueColor),
+            _ => Some(ColorMode::Ansi256),
+        }
+    }
+}
+
+/// Color of the entry `index` of the xterm 256 color palette, or `None` for the 16
+/// first entries whose colors depend on the terminal.
+pub fn ansi256_color(index: u8) -> Option<RGB<u8>> {
+    match index {
+        0..=15 => None,
+        16..=231 => {
+            let i = usize::from(index - 16);
+            Some(RGB {
+                r: CUBE_LEVELS[i / 36],
+                g: CUBE_LEVELS[i / 6 % 6],
+                b: CUBE_LEVELS[i % 6],
+            })
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            Some(RGB {
+                r: level,
+                g: level,
+                b: level,
+            })
+        }
+    }
+}
+
+/// CIELAB coordinates of the entries 16 to 255 of the xterm 256 color palette,
+/// computed on first use.
+fn ansi256_labs() -> &'static [[f64; 3]; 240] {
+    static LABS: OnceLock<[[f64; 3]; 240]> = OnceLock::new();
+    LABS.get_or_init(|| {
+        let mut labs = [[0.; 3]; 240];
+        for (index, lab) in (16..=255).zip(labs.iter_mut()) {
+            *lab = rgb_to_lab(ansi256_color(index).unwrap());
+        }
+        labs
+    })
+}
+
+/// Index of the color of the xterm 256 color palette closest to `color` (by CIE76 ΔE),
+/// ignoring the 16 first entries whose colors depend on the terminal.
+pub fn to_ansi256(color: RGB<u8>) -> u8 {
+    let lab = rgb_to_lab(color);
+    let (offset, _) = ansi256_labs()
+        .iter()
+        .map(|other| (0..3).map(|i| (lab[i] - other[i]).powi(2)).sum::<f64>())
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    16 + offset as u8
+}
+
+fn sequence(layer: u8, color: RGB<u8>, mode: ColorMode) -> String {
+    match mode {
+        ColorMode::TrueColor => format!("\x1b[{};2;{};{};{}m", layer, color.r, color.g, color.b),
+        ColorMode::Ansi256 => format!("\x1b[{};5;{}m", layer, to_ansi256(color)),
+    }
+}
+
+/// Escape sequence setting the foreground (text) color.
+pub fn foreground(color: RGB<u8>, mode: ColorMode) -> String {
+    sequence(38, color, mode)
+}
+
+/// Escape sequence setting the background color.
+pub fn background(color: RGB<u8>, mode: ColorMode) -> String {
+    sequence(48, color, mode)
+}
+
+/// Black or white, whichever is the most readable on `color`.
+pub fn contrasting_text(color: RGB<u8>) -> RGB<u8> {
+    if rgb_to_lab(color)[0] > 55. {
+        RGB { r: 0, g: 0, b: 0 }
+    } else {
+        RGB {
+            r: 255,
+            g: 255,
+            b: 255,
+        }
+    }
+}
+
+/// `text` written in `color`, followed by a reset.
+pub fn paint(text: &str, color: RGB<u8>, mode: ColorMode) -> String {
+    format!("{}{}{}", foreground(color, mode), text, RESET)
+}
+
+/// `text` written on a `color` background, in black or white (see [`contrasting_text`]),
+/// followed by a reset.
+pub fn highlight(text: &str, color: RGB<u8>, mode: ColorMode) -> String {
+    format!(
+        "{}{}{}{}",
+        background(color, mode),
+        foreground(contrasting_text(color), mode),
+        text,
+        RESET
+    )
+}
+
+/// The colors as blocks of `width` spaces on a background of each color.
+pub fn swatches(colors: &[RGB<u8>], width: usize, mode: ColorMode) -> String {
+    let block = " ".repeat(width);
+    colors
+        .iter()
+        .map(|&color| format!("{}{}{}", background(color, mode), block, RESET))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Scale {
+    Classes(Classifier),
+    Continuous {
+        colormap: Colormap,
+        min: f64,
+        max: f64,
+    },
+}
+
+/// Colors some text according to a value, for example the cells of a table printed
+/// in the terminal, with the color of its class or a continuous color map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Painter {
+    scale: Scale,
+    mode: ColorMode,
+    background: bool,
+}
+
+impl Painter {
+    /// Painter using the color of the class of each value (see [`Classifier::color_for`]).
+    pub fn classes(classifier: Classifier) -> Self {
+        Painter {
+            scale: Scale::Classes(classifier),
+            mode: ColorMode::default(),
+            background: true,
+        }
+    }
+
+    /// Painter evaluating `colormap` at the position of each value between `min`
+    /// and `max` (clamped). Missing values (`NaN`) get a light grey.
+    pub fn continuous(colormap: Colormap, min: f64, max: f64) -> Self {
+        Painter {
+            scale: Scale::Continuous { colormap, min, max },
+            mode: ColorMode::default(),
+            background: true,
+        }
+    }
+
+    /// Set how the colors are written (24-bit colors by default).
+    pub fn mode(mut self, mode: ColorMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Color the background of the text (the default), or the text itself if `false`.
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Color of `value`.
+    pub fn color_for(&self, value: f64) -> RGB<u8> {
+        match &self.scale {
+            Scale::Classes(classifier) => classifier.color_for(value),
+            Scale::Continuous { .. } if value.is_nan() => DEFAULT_NO_DATA,
+            Scale::Continuous { colormap, min, max } => {
+                let t = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.5
+                };
+                colormap.eval(t)
+            }
+        }
+    }
+
+    /// `text` colored according to `value`, followed by a reset.
+    pub fn paint(&self, text: &str, value: f64) -> String {
+        let color = self.color_for(value);
+        if self.background {
+            highlight(text, color, self.mode)
+        } else {
+            paint(text, color, self.mode)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Interpolation, Palette};
+
+    #[test]
+    fn test_ansi256() {
+        assert_eq!(ansi256_color(7), None);
+        assert_eq!(ansi256_color(16), Some(RGB { r: 0, g: 0, b: 0 }));
+        assert_eq!(ansi256_color(196), Some(RGB { r: 255, g: 0, b: 0 }));
+        assert_eq!(
+            ansi256_color(255),
+            Some(RGB {
+                r: 238,
+                g: 238,
+                b: 238
+            })
+        );
+        for index in 16..=255 {
+            let color = ansi256_color(index).unwrap();
+            assert_eq!(ansi256_color(to_ansi256(color)), Some(color));
+        }
+        assert_eq!(
+            to_ansi256(RGB {
+                r: 250,
+                g: 2,
+                b: 10
+            }),
+            196
+        );
+        assert_eq!(
+            to_ansi256(RGB {
+                r: 120,
+                g: 121,
+                b: 119
+            }),
+            243
+        );
+    }
+
+    #[test]
+    fn test_sequences() {
+        let color = RGB {
+            r: 49,
+            g: 130,
+            b: 189,
+        };
+        assert_eq!(
+            paint("a", color, ColorMode::TrueColor),
+            "\x1b[38;2;49;130;189ma\x1b[0m"
+        );
+        assert_eq!(
+            background(color, ColorMode::Ansi256),
+            format!("\x1b[48;5;{}m", to_ansi256(color))
+        );
+        assert_eq!(
+            highlight(
+                "a",
+                RGB {
+                    r: 255,
+                    g: 255,
+                    b: 204
+                },
+                ColorMode::TrueColor
+            ),
+            "\x1b[48;2;255;255;204m\x1b[38;2;0;0;0ma\x1b[0m"
+        );
+        assert_eq!(
+            swatches(&[color, color], 1, ColorMode::TrueColor),
+            "\x1b[48;2;49;130;189m \x1b[0m\x1b[48;2;49;130;189m \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_painter() {
+        let classifier = Classifier::new(Palette::Blues, 3, vec![0., 1., 2., 3.]).unwrap();
+        let painter = Painter::classes(classifier).background(false);
+        let colors = Palette::Blues.colors(3).unwrap();
+        assert_eq!(painter.color_for(2.5), colors[2]);
+        assert_eq!(
+            painter.paint("x", 0.5),
+            paint("x", colors[0], ColorMode::TrueColor)
+        );
+
+        let colormap = Palette::Greens.colormap(Interpolation::Srgb);
+        let painter = Painter::continuous(colormap.clone(), 10., 20.);
+        assert_eq!(painter.color_for(15.), colormap.eval(0.5));
+        assert_eq!(painter.color_for(30.), colormap.eval(1.));
+        assert_eq!(painter.color_for(f64::NAN), DEFAULT_NO_DATA);
+        let painter = Painter::continuous(colormap.clone(), 1., 1.);
+        assert_eq!(painter.color_for(1.), colormap.eval(0.5));
+    }
+}
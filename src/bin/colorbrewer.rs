@@ -0,0 +1,374 @@
+//! Command-line tool listing, printing, previewing and exporting the ColorBrewer palettes
+//! (requires the `cli` feature).
+
+use clap::{Parser, Subcommand, ValueEnum};
+use colorbrewer::ansi::{swatches, ColorMode};
+use colorbrewer::export::{aco, ase, cpt, gpl, mapbox, qgis, sld, NamedRamp};
+use colorbrewer::format::{to_css, to_hex};
+use colorbrewer::legend::Legend;
+use colorbrewer::{Classifier, Palette, SchemeType};
+use rgb::RGB;
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+
+#[derive(Parser)]
+#[command(
+    name = "colorbrewer",
+    version,
+    about = "Query and export the ColorBrewer palettes"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the palettes, optionally filtered by scheme type, number of colors and properties
+    List {
+        /// Only list the palettes of this scheme type
+        #[arg(long, value_enum)]
+        kind: Option<Kind>,
+        /// Only list the palettes defining a ramp with this number of colors
+        #[arg(long)]
+        classes: Option<u32>,
+        /// Only list the colorblind safe palettes
+        #[arg(long)]
+        colorblind_safe: bool,
+        /// Only list the print friendly palettes
+        #[arg(long)]
+        print_friendly: bool,
+        /// Only list the photocopy safe palettes
+        #[arg(long)]
+        photocopy_safe: bool,
+        /// Only list the LCD friendly palettes
+        #[arg(long)]
+        lcd_friendly: bool,
+        /// Preview the colors of each palette
+        #[arg(long)]
+        preview: bool,
+    },
+    /// Print the colors of a ramp, one per line (or as a JSON array)
+    Show {
+        /// Name of the palette (case-insensitive, with a `_r` suffix to reverse it)
+        palette: String,
+        /// Number of colors
+        classes: u32,
+        /// Output format of the colors
+        #[arg(short, long, value_enum, default_value_t = ColorFormat::Hex)]
+        format: ColorFormat,
+    },
+    /// Preview the ramps of a palette in the terminal (with 256 colors if `COLORTERM` doesn't
+    /// advertise true color support, as hexadecimal codes if not written to a terminal)
+    Preview {
+        /// Name of the palette (case-insensitive, with a `_r` suffix to reverse it)
+        palette: String,
+        /// Number of colors (all the ramps of the palette are shown by default)
+        classes: Option<u32>,
+    },
+    /// Export a ramp (or, without palette, every palette) in the format of another tool
+    Export {
+        /// Output format
+        #[arg(value_enum)]
+        format: ExportFormat,
+        /// Name of the palette (case-insensitive, with a `_r` suffix to reverse it)
+        palette: Option<String>,
+        /// Number of colors
+        classes: Option<u32>,
+        /// Comma-separated class breaks (`0, 1, ..., classes` by default)
+        #[arg(long, value_delimiter = ',', allow_hyphen_values = true)]
+        breaks: Option<Vec<f64>>,
+        /// Attribute classified by the map styles
+        #[arg(long, default_value = "value")]
+        attribute: String,
+        /// Output file (standard output by default), or output directory of the CPT
+        /// library (one file per ramp)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Kind {
+    Sequential,
+    Diverging,
+    Qualitative,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorFormat {
+    /// `#rrggbb`
+    Hex,
+    /// `r,g,b`
+    Rgb,
+    /// JSON array of `#rrggbb` strings
+    Json,
+    /// CSS `rgb(r, g, b)`
+    Css,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ExportFormat {
+    /// GIMP palette
+    Gpl,
+    /// Adobe Swatch Exchange
+    Ase,
+    /// Photoshop swatches
+    Aco,
+    /// GMT color palette table
+    Cpt,
+    /// QGIS graduated symbol layer style (or color ramp library without palette)
+    Qgis,
+    /// OGC SLD FeatureTypeStyle
+    Sld,
+    /// Mapbox GL step expression
+    MapboxStep,
+    /// Mapbox GL interpolate expression
+    MapboxInterpolate,
+    /// SVG legend
+    Svg,
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        let broken_pipe = err
+            .downcast_ref::<io::Error>()
+            .is_some_and(|err| err.kind() == io::ErrorKind::BrokenPipe);
+        if broken_pipe {
+            return;
+        }
+        eprintln!("colorbrewer: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    match cli.command {
+        Command::List {
+            kind,
+            classes,
+            colorblind_safe,
+            print_friendly,
+            photocopy_safe,
+            lcd_friendly,
+            preview,
+        } => {
+            let mut filter = Palette::filter();
+            if let Some(kind) = kind {
+                filter = filter.kind(match kind {
+                    Kind::Sequential => SchemeType::Sequential,
+                    Kind::Diverging => SchemeType::Diverging,
+                    Kind::Qualitative => SchemeType::Qualitative,
+                });
+            }
+            if let Some(classes) = classes {
+                filter = filter.classes(classes);
+            }
+            if colorblind_safe {
+                filter = filter.colorblind_safe();
+            }
+            if print_friendly {
+                filter = filter.print_friendly();
+            }
+            if photocopy_safe {
+                filter = filter.photocopy_safe();
+            }
+            if lcd_friendly {
+                filter = filter.lcd_friendly();
+            }
+            for palette in filter.iter() {
+                let kind = format!("{:?}", palette.scheme_type()).to_lowercase();
+                let range = format!("{}-{}", palette.min_classes(), palette.max_classes());
+                if preview {
+                    let nb_value = classes.unwrap_or_else(|| palette.max_classes());
+                    let colors = palette.colors(nb_value).unwrap();
+                    writeln!(
+                        out,
+                        "{:<9} {:<12} {:<5} {}",
+                        palette,
+                        kind,
+                        range,
+                        blocks(colors)
+                    )?;
+                } else {
+                    writeln!(out, "{:<9} {:<12} {}", palette, kind, range)?;
+                }
+            }
+        }
+        Command::Show {
+            palette,
+            classes,
+            format,
+        } => {
+            let colors = ramp_colors(&palette, classes)?;
+            writeln!(out, "{}", format_colors(&colors, format))?;
+        }
+        Command::Preview { palette, classes } => {
+            let parsed = Palette::parse_lenient(&palette)?;
+            let counts = match classes {
+                Some(classes) => classes..=classes,
+                None => parsed.palette.min_classes()..=parsed.palette.max_classes(),
+            };
+            for nb_value in counts {
+                let colors = ramp_colors(&palette, nb_value)?;
+                writeln!(out, "{:>2} {}", nb_value, blocks(&colors))?;
+            }
+        }
+        Command::Export {
+            format,
+            palette,
+            classes,
+            breaks,
+            attribute,
+            output,
+        } => {
+            let content = match (palette, classes) {
+                (Some(palette), Some(classes)) => {
+                    let name = ramp_name(&palette, classes)?;
+                    let colors = ramp_colors(&palette, classes)?;
+                    let breaks = breaks.unwrap_or_else(|| (0..=classes).map(f64::from).collect());
+                    let classifier = Classifier::from_colors(colors, breaks)?;
+                    export(format, &name, &classifier, &attribute)?
+                }
+                (None, None) if format == ExportFormat::Cpt => {
+                    let dir = output.ok_or(
+                        "the CPT library is written as one file per ramp, \
+                         give an output directory with --output",
+                    )?;
+                    std::fs::create_dir_all(&dir)?;
+                    for (file_name, content) in cpt::library() {
+                        std::fs::write(dir.join(file_name), content)?;
+                    }
+                    return Ok(());
+                }
+                (None, None) => export_library(format)?,
+                _ => return Err("expected both a palette and a number of colors".into()),
+            };
+            match output {
+                Some(path) => std::fs::write(path, content)?,
+                None => out.write_all(&content)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Colors of the ramp of the palette named `name` with `nb_value` colors,
+/// reversed if the name has a `_r` suffix.
+fn ramp_colors(name: &str, nb_value: u32) -> Result<Vec<RGB<u8>>, Box<dyn Error>> {
+    let parsed = Palette::parse_lenient(name)?;
+    let mut colors = parsed.palette.try_colors(nb_value)?.to_vec();
+    if parsed.reversed {
+        colors.reverse();
+    }
+    Ok(colors)
+}
+
+/// Name of the exported ramp, like `RdBu_5`, or `RdBu_r_5` when it is reversed.
+fn ramp_name(name: &str, nb_value: u32) -> Result<String, Box<dyn Error>> {
+    let parsed = Palette::parse_lenient(name)?;
+    let suffix = if parsed.reversed { "_r" } else { "" };
+    Ok(format!("{}{}_{}", parsed.palette, suffix, nb_value))
+}
+
+fn format_colors(colors: &[RGB<u8>], format: ColorFormat) -> String {
+    let formatted: Vec<String> = colors
+        .iter()
+        .map(|&color| match format {
+            ColorFormat::Hex => to_hex(color),
+            ColorFormat::Rgb => format!("{},{},{}", color.r, color.g, color.b),
+            ColorFormat::Json => format!("\"{}\"", to_hex(color)),
+            ColorFormat::Css => to_css(color),
+        })
+        .collect();
+    match format {
+        ColorFormat::Json => format!("[{}]", formatted.join(", ")),
+        _ => formatted.join("\n"),
+    }
+}
+
+/// The colors as blocks of ANSI background colors, or as hexadecimal codes
+/// when the standard output is not a terminal (or `NO_COLOR` is set).
+fn blocks(colors: &[RGB<u8>]) -> String {
+    match ColorMode::detect() {
+        Some(mode) => swatches(colors, 3, mode),
+        None => colors
+            .iter()
+            .map(|&color| to_hex(color))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
+fn export(
+    format: ExportFormat,
+    name: &str,
+    classifier: &Classifier,
+    attribute: &str,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let colors = classifier.colors();
+    Ok(match format {
+        ExportFormat::Gpl => gpl::write(name, colors).into_bytes(),
+        ExportFormat::Ase => ase::write(&[NamedRamp {
+            name: name.to_owned(),
+            ramp: colors.into(),
+        }])?,
+        ExportFormat::Aco => aco::write(colors)?,
+        ExportFormat::Cpt => cpt::write(name, classifier).into_bytes(),
+        ExportFormat::Qgis => qgis::classifier_style(classifier, attribute).into_bytes(),
+        ExportFormat::Sld => sld::classifier_style(classifier, attribute).into_bytes(),
+        ExportFormat::MapboxStep => {
+            mapbox::classifier_step_expression(classifier, attribute).into_bytes()
+        }
+        ExportFormat::MapboxInterpolate => {
+            mapbox::classifier_interpolate_expression(classifier, attribute).into_bytes()
+        }
+        ExportFormat::Svg => Legend::from_classifier(classifier).to_svg().into_bytes(),
+    })
+}
+
+fn export_library(format: ExportFormat) -> Result<Vec<u8>, Box<dyn Error>> {
+    match format {
+        ExportFormat::Gpl => Ok(gpl::library().into_bytes()),
+        ExportFormat::Ase => Ok(ase::library()),
+        ExportFormat::Aco => Ok(aco::library()),
+        ExportFormat::Qgis => Ok(qgis::color_ramp_library().into_bytes()),
+        _ => Err("this format needs a palette and a number of colors".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_colors() {
+        let colors = ramp_colors("blues_r", 3).unwrap();
+        assert_eq!(
+            format_colors(&colors, ColorFormat::Hex),
+            "#3182bd\n#9ecae1\n#deebf7"
+        );
+        assert_eq!(
+            format_colors(&colors[..2], ColorFormat::Json),
+            "[\"#3182bd\", \"#9ecae1\"]"
+        );
+        assert_eq!(format_colors(&colors[..1], ColorFormat::Rgb), "49,130,189");
+        assert!(ramp_colors("Blues", 12).is_err());
+    }
+
+    #[test]
+    fn test_ramp_name() {
+        assert_eq!(ramp_name("rdbu_r", 3).unwrap(), "RdBu_r_3");
+        assert_eq!(ramp_name("greys", 5).unwrap(), "Greys_5");
+    }
+
+    #[test]
+    fn test_cli() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+}
@@ -0,0 +1,353 @@
+//! Classification of numeric values into classes, for example to draw choropleth maps.
+//!
+//! Each method returns the class breaks: at most `nb_class + 1` strictly increasing values,
+//! the first one being the minimum and the last one the maximum of the values (unless
+//! stated otherwise). Fewer breaks are returned when the values can't be split into
+//! `nb_class` classes, for example when many of them are equal, so the number of classes
+//! should be taken from the length of the breaks. Values that are not finite (`NaN` or
+//! infinite) are ignored, and an error is returned if all the values are equal.
+//!
+//! ```rust
+//! use colorbrewer::classification::{breaks, Method};
+//!
+//! let values = [1., 2., 4., 5., 7., 8., 9., 10., 21., 25.];
+//! let result = breaks(&values, 3, Method::EqualInterval).unwrap();
+//! assert_eq!(result, vec![1., 9., 17., 25.]);
+//! ```
+
+use crate::{Error, Palette};
+
+/// Classification method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// Classes of equal width.
+    EqualInterval,
+    /// Classes containing the same number of values.
+    Quantile,
+    /// Jenks natural breaks, minimizing the variance inside the classes.
+    Jenks,
+    /// Classes one standard deviation wide, centered on the mean.
+    StandardDeviation,
+    /// Classes whose width grows geometrically (values must be positive).
+    Geometric,
+    /// Head/tail breaks, for heavy-tailed distributions.
+    HeadTail,
+}
+
+/// Compute the class breaks of `values` with the given `method`.
+pub fn breaks(values: &[f64], nb_class: u32, method: Method) -> Result<Vec<f64>, Error> {
+    match method {
+        Method::EqualInterval => equal_interval(values, nb_class),
+        Method::Quantile => quantile(values, nb_class),
+        Method::Jenks => jenks(values, nb_class),
+        Method::StandardDeviation => standard_deviation(values, nb_class),
+        Method::Geometric => geometric(values, nb_class),
+        Method::HeadTail => head_tail(values, nb_class),
+    }
+}
+
+/// Compute the class breaks of `values` with the given `method`, after checking that
+/// `palette` defines a color ramp with `nb_class` colors. The breaks may define fewer
+/// classes than `nb_class` (see the [module documentation](self)).
+///
+/// ```rust
+/// use colorbrewer::classification::{palette_breaks, Method};
+/// use colorbrewer::{Error, Palette};
+///
+/// let values = [1., 2., 4., 5., 7., 8., 9., 10., 21., 25.];
+/// assert!(palette_breaks(Palette::Blues, &values, 5, Method::Quantile).is_ok());
+/// assert!(matches!(
+///     palette_breaks(Palette::Blues, &values, 10, Method::Quantile),
+///     Err(Error::InvalidClassCount { .. })
+/// ));
+/// ```
+pub fn palette_breaks(
+    palette: Palette,
+    values: &[f64],
+    nb_class: u32,
+    method: Method,
+) -> Result<Vec<f64>, Error> {
+    palette.try_colors(nb_class)?;
+    breaks(values, nb_class, method)
+}
+
+/// Finite values, sorted in increasing order.
+fn sorted_values(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    if nb_class == 0 {
+        return Err(Error::InvalidClassification(
+            "the number of classes must be at least 1",
+        ));
+    }
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.is_empty() {
+        return Err(Error::InvalidClassification("no finite value to classify"));
+    }
+    sorted.sort_by(f64::total_cmp);
+    Ok(sorted)
+}
+
+/// Remove the repeated breaks, which would define empty classes.
+fn distinct_breaks(mut breaks: Vec<f64>) -> Result<Vec<f64>, Error> {
+    breaks.dedup();
+    if breaks.len() < 2 {
+        return Err(Error::InvalidClassification(
+            "the values must not all be equal",
+        ));
+    }
+    Ok(breaks)
+}
+
+/// Equal interval breaks: the range of the values is divided into classes of equal width.
+pub fn equal_interval(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = (max - min) / f64::from(nb_class);
+    let mut breaks: Vec<f64> = (0..nb_class).map(|i| min + width * f64::from(i)).collect();
+    breaks.push(max);
+    distinct_breaks(breaks)
+}
+
+/// Quantile breaks: each class contains (about) the same number of values.
+/// The inner breaks are linearly interpolated between the sorted values, and the
+/// quantiles falling on the same value are merged.
+pub fn quantile(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    let last = (sorted.len() - 1) as f64;
+    let breaks = (0..=nb_class)
+        .map(|i| {
+            let position = last * f64::from(i) / f64::from(nb_class);
+            let index = position.floor() as usize;
+            let frac = position - index as f64;
+            match sorted.get(index + 1) {
+                Some(next) if frac > 0. => sorted[index] + (next - sorted[index]) * frac,
+                _ => sorted[index],
+            }
+        })
+        .collect();
+    distinct_breaks(breaks)
+}
+
+/// Jenks natural breaks (Fisher-Jenks algorithm), minimizing the sum of the squared
+/// deviations from the class means. Each inner break is the greatest value of its class.
+///
+/// This needs at least `nb_class` finite values, and its cost grows with the square
+/// of the number of values. The number of classes is limited by the number of distinct
+/// values.
+pub fn jenks(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    if sorted.len() < nb_class as usize {
+        return Err(Error::InvalidClassification(
+            "there must be at least as many values as classes",
+        ));
+    }
+    let nb_distinct = 1 + sorted.windows(2).filter(|w| w[0] != w[1]).count();
+    let (n, k) = (sorted.len(), (nb_class as usize).min(nb_distinct));
+    // Matrices indexed from 1, as in the original algorithm:
+    // lower_limits[l][j] is the index of the first value of the last class
+    // of the optimal classification of the l first values into j classes.
+    let mut lower_limits = vec![vec![0usize; k + 1]; n + 1];
+    let mut variances = vec![vec![0f64; k + 1]; n + 1];
+    // Splitting l values into more than l classes is impossible.
+    for j in 1..=k {
+        lower_limits[1][j] = 1;
+        for (l, row) in variances.iter_mut().enumerate().skip(1) {
+            if l >= 2 || j >= 2 {
+                row[j] = f64::INFINITY;
+            }
+        }
+    }
+    for l in 2..=n {
+        let (mut sum, mut sum_squares, mut weight, mut variance) = (0., 0., 0., 0.);
+        for m in 1..=l {
+            let lower_limit = l - m + 1;
+            let value = sorted[lower_limit - 1];
+            weight += 1.;
+            sum += value;
+            sum_squares += value * value;
+            variance = sum_squares - (sum * sum) / weight;
+            let previous = lower_limit - 1;
+            if previous != 0 {
+                for j in 2..=k {
+                    let candidate = variance + variances[previous][j - 1];
+                    if variances[l][j] >= candidate {
+                        lower_limits[l][j] = lower_limit;
+                        variances[l][j] = candidate;
+                    }
+                }
+            }
+        }
+        lower_limits[l][1] = 1;
+        variances[l][1] = variance;
+    }
+    let mut breaks = vec![0.; k + 1];
+    breaks[0] = sorted[0];
+    breaks[k] = sorted[n - 1];
+    let mut end = n;
+    for j in (2..=k).rev() {
+        let lower_limit = lower_limits[end][j];
+        breaks[j - 1] = sorted[lower_limit - 2];
+        end = lower_limit - 1;
+    }
+    distinct_breaks(breaks)
+}
+
+/// Standard deviation breaks: classes one standard deviation wide, the mean being
+/// a break when `nb_class` is even or the center of a class when it is odd.
+/// The breaks falling outside of the range of the values are dropped, so fewer classes
+/// are returned when the values are not spread over `nb_class` standard deviations.
+pub fn standard_deviation(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let n = sorted.len() as f64;
+    let mean = sorted.iter().sum::<f64>() / n;
+    let std = (sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let half = f64::from(nb_class) / 2.;
+    let mut breaks = vec![min];
+    breaks.extend(
+        (1..nb_class)
+            .map(|i| mean + (f64::from(i) - half) * std)
+            .filter(|&b| b > min && b < max),
+    );
+    breaks.push(max);
+    distinct_breaks(breaks)
+}
+
+/// Geometric progression breaks: each class is wider than the previous one by
+/// a constant factor. All the values must be strictly positive.
+pub fn geometric(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    if min <= 0. {
+        return Err(Error::InvalidClassification(
+            "geometric breaks need strictly positive values",
+        ));
+    }
+    let ratio = (max / min).powf(1. / f64::from(nb_class));
+    let mut breaks: Vec<f64> = (0..nb_class).map(|i| min * ratio.powi(i as i32)).collect();
+    breaks.push(max);
+    distinct_breaks(breaks)
+}
+
+/// Head/tail breaks: the values are split around their mean, and the values above the
+/// mean (the head) are split again as long as they are a minority (less than 40 %)
+/// of the values being split.
+///
+/// The number of classes depends on the distribution of the values:
+/// `nb_class` is a maximum, fewer classes may be returned.
+pub fn head_tail(values: &[f64], nb_class: u32) -> Result<Vec<f64>, Error> {
+    let sorted = sorted_values(values, nb_class)?;
+    let mut breaks = vec![sorted[0]];
+    let mut data: &[f64] = &sorted;
+    while (breaks.len() as u32) < nb_class {
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        let head_start = data.partition_point(|&v| v <= mean);
+        let head = &data[head_start..];
+        if head.is_empty() || head.len() as f64 / data.len() as f64 >= 0.4 {
+            break;
+        }
+        breaks.push(mean);
+        data = head;
+    }
+    breaks.push(sorted[sorted.len() - 1]);
+    distinct_breaks(breaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [f64; 10] = [4., 2., 1., 5., 7., 8., 9., 10., 21., 25.];
+
+    #[test]
+    fn test_equal_interval() {
+        assert_eq!(
+            equal_interval(&VALUES, 4).unwrap(),
+            vec![1., 7., 13., 19., 25.]
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        assert_eq!(
+            quantile(&[1., 2., 3., 4., 5.], 4).unwrap(),
+            vec![1., 2., 3., 4., 5.]
+        );
+        assert_eq!(quantile(&VALUES, 2).unwrap(), vec![1., 7.5, 25.]);
+        assert_eq!(
+            quantile(&[0., 0., 0., 1., 3.], 4).unwrap(),
+            vec![0., 1., 3.]
+        );
+    }
+
+    #[test]
+    fn test_jenks() {
+        assert_eq!(jenks(&VALUES, 3).unwrap(), vec![1., 5., 10., 25.]);
+        let values = [1., 1., 2., 2., 3., 3., 10., 10., 11., 11.];
+        assert_eq!(jenks(&values, 2).unwrap(), vec![1., 3., 11.]);
+        assert!(jenks(&[1., 2.], 3).is_err());
+    }
+
+    #[test]
+    fn test_jenks_tied_values() {
+        assert!(jenks(&[3.; 10], 3).is_err());
+        assert!(jenks(&[1., 1., 1., 1.], 3).is_err());
+        assert_eq!(jenks(&[0., 0., 0., 0., 0., 10.], 4).unwrap(), vec![0., 10.]);
+        let values = [1., 1., 1., 2., 2., 2., 5., 5., 9., 9.];
+        assert_eq!(jenks(&values, 6).unwrap(), vec![1., 2., 5., 9.]);
+        assert_eq!(jenks(&values, 3).unwrap(), vec![1., 2., 5., 9.]);
+    }
+
+    #[test]
+    fn test_standard_deviation() {
+        let values = [2., 4., 4., 4., 5., 5., 7., 9.];
+        assert_eq!(
+            standard_deviation(&values, 4).unwrap(),
+            vec![2., 3., 5., 7., 9.]
+        );
+        assert_eq!(
+            standard_deviation(&values, 3).unwrap(),
+            vec![2., 4., 6., 9.]
+        );
+        let values = [1., 1., 1., 1., 1., 1., 1., 1., 10., 10.];
+        assert_eq!(
+            standard_deviation(&values, 6).unwrap(),
+            vec![1., 2.8, 6.4, 10.]
+        );
+    }
+
+    #[test]
+    fn test_geometric() {
+        let result = geometric(&[1., 10., 100., 1000.], 3).unwrap();
+        assert_eq!(result.len(), 4);
+        assert!((result[1] - 10.).abs() < 1e-9 && (result[2] - 100.).abs() < 1e-9);
+        assert!(geometric(&[0., 10.], 3).is_err());
+    }
+
+    #[test]
+    fn test_head_tail() {
+        let values = [1., 1., 1., 1., 1., 1., 1., 2., 3., 100.];
+        assert_eq!(head_tail(&values, 5).unwrap(), vec![1., 11.2, 100.]);
+        assert_eq!(head_tail(&values, 1).unwrap(), vec![1., 100.]);
+        let values: Vec<f64> = (1..=10).map(f64::from).collect();
+        assert_eq!(head_tail(&values, 5).unwrap(), vec![1., 10.]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(breaks(&[], 3, Method::Quantile).is_err());
+        assert!(breaks(&[f64::NAN], 3, Method::Quantile).is_err());
+        assert!(breaks(&VALUES, 0, Method::EqualInterval).is_err());
+        for method in [
+            Method::EqualInterval,
+            Method::Quantile,
+            Method::StandardDeviation,
+        ] {
+            assert!(breaks(&[2., 2., 2.], 3, method).is_err());
+        }
+        let with_nan = [1., f64::NAN, 25.];
+        assert_eq!(
+            breaks(&with_nan, 2, Method::EqualInterval).unwrap(),
+            vec![1., 13., 25.]
+        );
+    }
+}
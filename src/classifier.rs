@@ -0,0 +1,235 @@
+//! Mapping of values to the colors of their class.
+
+use crate::classification::{palette_breaks, Method};
+use crate::{Error, Palette};
+use rgb::RGB;
+
+/// Color used by default for the missing values (`NaN`).
+pub(crate) const DEFAULT_NO_DATA: RGB<u8> = RGB {
+    r: 204,
+    g: 204,
+    b: 204,
+};
+
+/// What to do with the values outside of the range of the breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutOfRange {
+    /// Use the color of the first class for the values below the first break,
+    /// and the one of the last class for the values above the last break.
+    #[default]
+    Clamp,
+    /// Use the no data color.
+    NoData,
+    /// Use a specific color below the first break and above the last break.
+    Colors {
+        /// Color of the values below the first break.
+        below: RGB<u8>,
+        /// Color of the values above the last break.
+        above: RGB<u8>,
+    },
+}
+
+/// Classifier mapping each value to the color of its class.
+///
+/// The classes are defined by `nb_class + 1` strictly increasing breaks, the class
+/// `i` containing the values greater than `breaks[i]` and lower or equal to
+/// `breaks[i + 1]` (the first class also contains `breaks[0]`).
+///
+/// ```rust
+/// use colorbrewer::{Classifier, Palette};
+///
+/// let classifier = Classifier::new(Palette::Blues, 3, vec![0., 10., 20., 30.]).unwrap();
+/// let colors = Palette::Blues.colors(3).unwrap();
+/// assert_eq!(classifier.color_for(5.), colors[0]);
+/// assert_eq!(classifier.color_for(10.), colors[0]);
+/// assert_eq!(classifier.color_for(25.), colors[2]);
+/// assert_eq!(classifier.colors_for(&[0., 15., 30.]), colors.to_vec());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classifier {
+    colors: Vec<RGB<u8>>,
+    breaks: Vec<f64>,
+    no_data: RGB<u8>,
+    out_of_range: OutOfRange,
+}
+
+impl Classifier {
+    /// Create a classifier using the ramp of `palette` with `nb_class` colors and
+    /// the given breaks. Return an error if the palette doesn't define this number of
+    /// colors or if `breaks` doesn't contain `nb_class + 1` strictly increasing finite
+    /// values.
+    pub fn new(palette: Palette, nb_class: u32, breaks: Vec<f64>) -> Result<Self, Error> {
+        Classifier::from_colors(palette.try_colors(nb_class)?.to_vec(), breaks)
+    }
+
+    /// Create a classifier with the breaks computed from `values` with the classification
+    /// `method`, and the ramp of `palette` with as many colors as classes.
+    ///
+    /// As the methods may return fewer classes than `nb_class` (see the
+    /// [`classification`](crate::classification) module), the classifier may have fewer
+    /// classes too. Return an error if the palette doesn't define `nb_class` colors, or
+    /// doesn't define a ramp for the number of classes actually found (for example a
+    /// single class for head/tail breaks of evenly distributed values).
+    pub fn from_values(
+        palette: Palette,
+        nb_class: u32,
+        values: &[f64],
+        method: Method,
+    ) -> Result<Self, Error> {
+        let breaks = palette_breaks(palette, values, nb_class, method)?;
+        Classifier::new(palette, breaks.len() as u32 - 1, breaks)
+    }
+
+    /// Create a classifier from arbitrary colors, one for each class.
+    /// Return an error if `breaks` doesn't contain one more value than `colors`
+    /// or if they are not strictly increasing finite values.
+    pub fn from_colors(colors: Vec<RGB<u8>>, breaks: Vec<f64>) -> Result<Self, Error> {
+        if colors.is_empty() || breaks.len() != colors.len() + 1 {
+            return Err(Error::InvalidClassification(
+                "there must be one more break than classes",
+            ));
+        }
+        if breaks.iter().any(|b| !b.is_finite()) || breaks.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Error::InvalidClassification(
+                "the breaks must be strictly increasing finite values",
+            ));
+        }
+        Ok(Classifier {
+            colors,
+            breaks,
+            no_data: DEFAULT_NO_DATA,
+            out_of_range: OutOfRange::default(),
+        })
+    }
+
+    /// Set the color of the missing values (`NaN`), `#cccccc` by default.
+    pub fn no_data_color(mut self, color: RGB<u8>) -> Self {
+        self.no_data = color;
+        self
+    }
+
+    /// Set how the values outside of the range of the breaks are handled
+    /// ([`OutOfRange::Clamp`] by default).
+    pub fn out_of_range(mut self, out_of_range: OutOfRange) -> Self {
+        self.out_of_range = out_of_range;
+        self
+    }
+
+    /// The class breaks.
+    pub fn breaks(&self) -> &[f64] {
+        &self.breaks
+    }
+
+    /// The colors of the classes.
+    pub fn colors(&self) -> &[RGB<u8>] {
+        &self.colors
+    }
+
+    /// Index of the class of `value`, or `None` if `value` is `NaN`
+    /// or outside of the range of the breaks.
+    pub fn class_of(&self, value: f64) -> Option<usize> {
+        let (first, last) = (self.breaks[0], self.breaks[self.breaks.len() - 1]);
+        if value.is_nan() || value < first || value > last {
+            return None;
+        }
+        Some(self.breaks[1..].partition_point(|&b| b < value))
+    }
+
+    /// Color of the class of `value`.
+    pub fn color_for(&self, value: f64) -> RGB<u8> {
+        if value.is_nan() {
+            return self.no_data;
+        }
+        if let Some(class) = self.class_of(value) {
+            return self.colors[class];
+        }
+        let below = value < self.breaks[0];
+        match self.out_of_range {
+            OutOfRange::Clamp if below => self.colors[0],
+            OutOfRange::Clamp => self.colors[self.colors.len() - 1],
+            OutOfRange::NoData => self.no_data,
+            OutOfRange::Colors { below: color, .. } if below => color,
+            OutOfRange::Colors { above: color, .. } => color,
+        }
+    }
+
+    /// Colors of the classes of each of the `values`.
+    pub fn colors_for(&self, values: &[f64]) -> Vec<RGB<u8>> {
+        values.iter().map(|&value| self.color_for(value)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::classification::Method;
+    use crate::{Classifier, OutOfRange, Palette};
+    use rgb::RGB;
+
+    #[test]
+    fn test_class_of() {
+        let classifier = Classifier::new(Palette::Reds, 4, vec![0., 1., 2., 3., 4.]).unwrap();
+        assert_eq!(classifier.class_of(0.), Some(0));
+        assert_eq!(classifier.class_of(1.), Some(0));
+        assert_eq!(classifier.class_of(1.5), Some(1));
+        assert_eq!(classifier.class_of(4.), Some(3));
+        assert_eq!(classifier.class_of(4.5), None);
+        assert_eq!(classifier.class_of(-0.5), None);
+        assert_eq!(classifier.class_of(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_no_data_and_out_of_range() {
+        let colors = Palette::Reds.colors(3).unwrap();
+        let classifier = Classifier::new(Palette::Reds, 3, vec![0., 1., 2., 3.]).unwrap();
+        assert_eq!(classifier.color_for(f64::NAN), RGB::new(204, 204, 204));
+        assert_eq!(classifier.color_for(-1.), colors[0]);
+        assert_eq!(classifier.color_for(10.), colors[2]);
+
+        let black = RGB::new(0, 0, 0);
+        let classifier = classifier
+            .no_data_color(black)
+            .out_of_range(OutOfRange::NoData);
+        assert_eq!(classifier.color_for(f64::NAN), black);
+        assert_eq!(classifier.color_for(10.), black);
+
+        let white = RGB::new(255, 255, 255);
+        let classifier = classifier.out_of_range(OutOfRange::Colors {
+            below: white,
+            above: black,
+        });
+        assert_eq!(
+            classifier.colors_for(&[-1., 0.5, 10.]),
+            vec![white, colors[0], black]
+        );
+    }
+
+    #[test]
+    fn test_invalid_breaks() {
+        assert!(Classifier::new(Palette::Reds, 3, vec![0., 1., 2.]).is_err());
+        assert!(Classifier::new(Palette::Reds, 3, vec![0., 2., 1., 3.]).is_err());
+        assert!(Classifier::new(Palette::Reds, 3, vec![0., 1., 1., 3.]).is_err());
+        assert!(Classifier::new(Palette::Reds, 3, vec![0., 1., 2., f64::NAN]).is_err());
+        assert!(Classifier::new(Palette::Reds, 2, vec![0., 1., 2.]).is_err());
+    }
+
+    #[test]
+    fn test_from_values() {
+        let values = [1., 2., 4., 5., 7., 8., 9., 10., 21., 25.];
+        let classifier =
+            Classifier::from_values(Palette::Greens, 3, &values, Method::Jenks).unwrap();
+        assert_eq!(classifier.breaks(), &[1., 5., 10., 25.]);
+        assert_eq!(
+            classifier.color_for(21.),
+            Palette::Greens.colors(3).unwrap()[2]
+        );
+
+        // Head/tail breaks find 3 classes here.
+        let mut values = vec![1.; 30];
+        values.extend([20., 30., 40., 50., 1000.]);
+        let classifier =
+            Classifier::from_values(Palette::Reds, 5, &values, Method::HeadTail).unwrap();
+        assert_eq!(classifier.colors(), Palette::Reds.colors(3).unwrap());
+        let values: Vec<f64> = (1..=10).map(f64::from).collect();
+        assert!(Classifier::from_values(Palette::Reds, 5, &values, Method::HeadTail).is_err());
+    }
+}
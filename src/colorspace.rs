@@ -0,0 +1,453 @@
+//! Conversions between 8-bit sRGB colors and other color spaces.
+//!
+//! The colors are converted to `[f64; 3]` components (`[f64; 4]` for CMYK):
+//!
+//! - linear sRGB, HSL, HSV and CMYK components are in `[0, 1]`, hues in degrees in `[0, 360)`;
+//! - CIE XYZ is normalized so that the white has `Y = 1`;
+//! - CIELAB and LCh lightness is in `[0, 100]`, OKLab and OKLCh lightness in `[0, 1]`.
+//!
+//! All the conversions use the D65 white point. The conversions back to `RGB<u8>`
+//! clamp the colors outside of the sRGB gamut.
+//!
+//! ```rust
+//! use colorbrewer::colorspace::{lab_to_rgb, rgb_to_hsl, rgb_to_lab, ColorSpace};
+//! use colorbrewer::Palette;
+//!
+//! let color = Palette::Greens.colors(3).unwrap()[2];
+//! let lab = rgb_to_lab(color);
+//! assert_eq!(lab_to_rgb(lab), color);
+//! let [hue, _, _] = rgb_to_hsl(color);
+//! assert!(hue > 90. && hue < 180.);
+//!
+//! let linear = Palette::Greens.ramp(3).unwrap().to_color_space(ColorSpace::LinearRgb);
+//! assert_eq!(linear.len(), 3);
+//! ```
+
+use crate::Ramp;
+use rgb::RGB;
+
+/// D65 reference white, in CIE XYZ (Y normalized to 1).
+const WHITE_D65: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// Convert a gamma-encoded sRGB component in `[0, 1]` to linear light.
+pub fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light component in `[0, 1]` to gamma-encoded sRGB.
+pub fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Normalize an 8-bit sRGB color to `[0, 1]` components.
+pub(crate) fn rgb_to_unit(color: RGB<u8>) -> [f64; 3] {
+    [
+        f64::from(color.r) / 255.,
+        f64::from(color.g) / 255.,
+        f64::from(color.b) / 255.,
+    ]
+}
+
+/// Quantize `[0, 1]` sRGB components to an 8-bit color, clamping out of gamut values.
+pub(crate) fn unit_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    let quantize = |v: f64| (v.clamp(0., 1.) * 255.).round() as u8;
+    RGB {
+        r: quantize(c[0]),
+        g: quantize(c[1]),
+        b: quantize(c[2]),
+    }
+}
+
+/// Convert a color to linear sRGB.
+pub fn rgb_to_linear(color: RGB<u8>) -> [f64; 3] {
+    let [r, g, b] = rgb_to_unit(color);
+    [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)]
+}
+
+/// Convert a linear sRGB color to an 8-bit sRGB color.
+pub fn linear_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    unit_to_rgb([
+        linear_to_srgb(c[0].max(0.)),
+        linear_to_srgb(c[1].max(0.)),
+        linear_to_srgb(c[2].max(0.)),
+    ])
+}
+
+pub(crate) fn linear_to_xyz(c: [f64; 3]) -> [f64; 3] {
+    [
+        0.412_456_4 * c[0] + 0.357_576_1 * c[1] + 0.180_437_5 * c[2],
+        0.212_672_9 * c[0] + 0.715_152_2 * c[1] + 0.072_175_0 * c[2],
+        0.019_333_9 * c[0] + 0.119_192_0 * c[1] + 0.950_304_1 * c[2],
+    ]
+}
+
+pub(crate) fn xyz_to_linear(c: [f64; 3]) -> [f64; 3] {
+    [
+        3.240_454_2 * c[0] - 1.537_138_5 * c[1] - 0.498_531_4 * c[2],
+        -0.969_266_0 * c[0] + 1.876_010_8 * c[1] + 0.041_556_0 * c[2],
+        0.055_643_4 * c[0] - 0.204_025_9 * c[1] + 1.057_225_2 * c[2],
+    ]
+}
+
+const LAB_EPSILON: f64 = 216. / 24389.;
+const LAB_KAPPA: f64 = 24389. / 27.;
+
+pub(crate) fn xyz_to_lab(c: [f64; 3]) -> [f64; 3] {
+    let f = |t: f64| {
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.) / 116.
+        }
+    };
+    let fx = f(c[0] / WHITE_D65[0]);
+    let fy = f(c[1] / WHITE_D65[1]);
+    let fz = f(c[2] / WHITE_D65[2]);
+    [116. * fy - 16., 500. * (fx - fy), 200. * (fy - fz)]
+}
+
+pub(crate) fn lab_to_xyz(c: [f64; 3]) -> [f64; 3] {
+    let fy = (c[0] + 16.) / 116.;
+    let fx = fy + c[1] / 500.;
+    let fz = fy - c[2] / 200.;
+    let f_inv = |t: f64| {
+        let t3 = t * t * t;
+        if t3 > LAB_EPSILON {
+            t3
+        } else {
+            (116. * t - 16.) / LAB_KAPPA
+        }
+    };
+    [
+        f_inv(fx) * WHITE_D65[0],
+        f_inv(fy) * WHITE_D65[1],
+        f_inv(fz) * WHITE_D65[2],
+    ]
+}
+
+/// Convert a color to CIE XYZ.
+pub fn rgb_to_xyz(color: RGB<u8>) -> [f64; 3] {
+    linear_to_xyz(rgb_to_linear(color))
+}
+
+/// Convert a CIE XYZ color to an 8-bit sRGB color.
+pub fn xyz_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    linear_to_rgb(xyz_to_linear(c))
+}
+
+/// Convert a color to CIELAB.
+pub fn rgb_to_lab(color: RGB<u8>) -> [f64; 3] {
+    xyz_to_lab(linear_to_xyz(rgb_to_linear(color)))
+}
+
+/// Convert a CIELAB color to an 8-bit sRGB color.
+pub fn lab_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    linear_to_rgb(xyz_to_linear(lab_to_xyz(c)))
+}
+
+pub(crate) fn linear_to_oklab(c: [f64; 3]) -> [f64; 3] {
+    let l = (0.412_221_470_8 * c[0] + 0.536_332_536_3 * c[1] + 0.051_445_992_9 * c[2]).cbrt();
+    let m = (0.211_903_498_2 * c[0] + 0.680_699_545_1 * c[1] + 0.107_396_956_6 * c[2]).cbrt();
+    let s = (0.088_302_461_9 * c[0] + 0.281_718_837_6 * c[1] + 0.629_978_700_5 * c[2]).cbrt();
+    [
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    ]
+}
+
+pub(crate) fn oklab_to_linear(c: [f64; 3]) -> [f64; 3] {
+    let l = c[0] + 0.396_337_777_4 * c[1] + 0.215_803_757_3 * c[2];
+    let m = c[0] - 0.105_561_345_8 * c[1] - 0.063_854_172_8 * c[2];
+    let s = c[0] - 0.089_484_177_5 * c[1] - 1.291_485_548_0 * c[2];
+    let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+    [
+        4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+        -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+        -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+    ]
+}
+
+/// Convert a color to OKLab.
+pub fn rgb_to_oklab(color: RGB<u8>) -> [f64; 3] {
+    linear_to_oklab(rgb_to_linear(color))
+}
+
+/// Convert an OKLab color to an 8-bit sRGB color.
+pub fn oklab_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    linear_to_rgb(oklab_to_linear(c))
+}
+
+/// Convert Lab-like components to polar coordinates (lightness, chroma, hue in degrees).
+fn lab_to_polar(c: [f64; 3]) -> [f64; 3] {
+    let hue = if c[1] == 0. && c[2] == 0. {
+        0.
+    } else {
+        c[2].atan2(c[1]).to_degrees().rem_euclid(360.)
+    };
+    [c[0], c[1].hypot(c[2]), hue]
+}
+
+fn polar_to_lab(c: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = c[2].to_radians().sin_cos();
+    [c[0], c[1] * cos, c[1] * sin]
+}
+
+/// Convert a color to CIE LCh (the polar form of CIELAB).
+pub fn rgb_to_lch(color: RGB<u8>) -> [f64; 3] {
+    lab_to_polar(rgb_to_lab(color))
+}
+
+/// Convert a CIE LCh color to an 8-bit sRGB color.
+pub fn lch_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    lab_to_rgb(polar_to_lab(c))
+}
+
+/// Convert a color to OKLCh (the polar form of OKLab).
+pub fn rgb_to_oklch(color: RGB<u8>) -> [f64; 3] {
+    lab_to_polar(rgb_to_oklab(color))
+}
+
+/// Convert an OKLCh color to an 8-bit sRGB color.
+pub fn oklch_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    oklab_to_rgb(polar_to_lab(c))
+}
+
+/// Hue (in degrees), maximum and minimum of the components of a color.
+fn hue_max_min(color: RGB<u8>) -> (f64, f64, f64) {
+    let [r, g, b] = rgb_to_unit(color);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0. {
+        0.
+    } else if max == r {
+        60. * ((g - b) / delta).rem_euclid(6.)
+    } else if max == g {
+        60. * ((b - r) / delta + 2.)
+    } else {
+        60. * ((r - g) / delta + 4.)
+    };
+    (hue, max, min)
+}
+
+/// sRGB components of a color from its hue (in degrees), chroma and the value
+/// of its smallest component.
+fn hue_chroma_to_rgb(hue: f64, chroma: f64, min: f64) -> RGB<u8> {
+    let h = hue.rem_euclid(360.) / 60.;
+    let x = chroma * (1. - (h.rem_euclid(2.) - 1.).abs());
+    let [r, g, b] = match h as u32 {
+        0 => [chroma, x, 0.],
+        1 => [x, chroma, 0.],
+        2 => [0., chroma, x],
+        3 => [0., x, chroma],
+        4 => [x, 0., chroma],
+        _ => [chroma, 0., x],
+    };
+    unit_to_rgb([r + min, g + min, b + min])
+}
+
+/// Convert a color to HSL (hue, saturation, lightness).
+pub fn rgb_to_hsl(color: RGB<u8>) -> [f64; 3] {
+    let (hue, max, min) = hue_max_min(color);
+    let lightness = (max + min) / 2.;
+    let saturation = if max == min {
+        0.
+    } else {
+        (max - min) / (1. - (2. * lightness - 1.).abs())
+    };
+    [hue, saturation, lightness]
+}
+
+/// Convert an HSL color to an 8-bit sRGB color.
+pub fn hsl_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    let [hue, saturation, lightness] = c;
+    let chroma = (1. - (2. * lightness - 1.).abs()) * saturation;
+    hue_chroma_to_rgb(hue, chroma, lightness - chroma / 2.)
+}
+
+/// Convert a color to HSV (hue, saturation, value).
+pub fn rgb_to_hsv(color: RGB<u8>) -> [f64; 3] {
+    let (hue, max, min) = hue_max_min(color);
+    let saturation = if max == 0. { 0. } else { (max - min) / max };
+    [hue, saturation, max]
+}
+
+/// Convert an HSV color to an 8-bit sRGB color.
+pub fn hsv_to_rgb(c: [f64; 3]) -> RGB<u8> {
+    let [hue, saturation, value] = c;
+    let chroma = value * saturation;
+    hue_chroma_to_rgb(hue, chroma, value - chroma)
+}
+
+/// Convert a color to CMYK with the naive device-independent formula,
+/// without any ink or paper profile.
+pub fn rgb_to_cmyk(color: RGB<u8>) -> [f64; 4] {
+    let [r, g, b] = rgb_to_unit(color);
+    let k = 1. - r.max(g).max(b);
+    if k >= 1. {
+        return [0., 0., 0., 1.];
+    }
+    [
+        (1. - r - k) / (1. - k),
+        (1. - g - k) / (1. - k),
+        (1. - b - k) / (1. - k),
+        k,
+    ]
+}
+
+/// Convert a CMYK color to an 8-bit sRGB color with the naive formula.
+pub fn cmyk_to_rgb(c: [f64; 4]) -> RGB<u8> {
+    let [cyan, magenta, yellow, k] = c;
+    unit_to_rgb([
+        (1. - cyan) * (1. - k),
+        (1. - magenta) * (1. - k),
+        (1. - yellow) * (1. - k),
+    ])
+}
+
+/// Color space with three components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Linear sRGB.
+    LinearRgb,
+    /// Hue, saturation, lightness.
+    Hsl,
+    /// Hue, saturation, value.
+    Hsv,
+    /// CIE XYZ.
+    Xyz,
+    /// CIELAB.
+    Lab,
+    /// CIE LCh, the polar form of CIELAB.
+    Lch,
+    /// OKLab.
+    Oklab,
+    /// OKLCh, the polar form of OKLab.
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Convert an 8-bit sRGB color to this color space.
+    pub fn from_rgb(self, color: RGB<u8>) -> [f64; 3] {
+        match self {
+            ColorSpace::LinearRgb => rgb_to_linear(color),
+            ColorSpace::Hsl => rgb_to_hsl(color),
+            ColorSpace::Hsv => rgb_to_hsv(color),
+            ColorSpace::Xyz => rgb_to_xyz(color),
+            ColorSpace::Lab => rgb_to_lab(color),
+            ColorSpace::Lch => rgb_to_lch(color),
+            ColorSpace::Oklab => rgb_to_oklab(color),
+            ColorSpace::Oklch => rgb_to_oklch(color),
+        }
+    }
+
+    /// Convert a color of this color space to an 8-bit sRGB color.
+    pub fn to_rgb(self, c: [f64; 3]) -> RGB<u8> {
+        match self {
+            ColorSpace::LinearRgb => linear_to_rgb(c),
+            ColorSpace::Hsl => hsl_to_rgb(c),
+            ColorSpace::Hsv => hsv_to_rgb(c),
+            ColorSpace::Xyz => xyz_to_rgb(c),
+            ColorSpace::Lab => lab_to_rgb(c),
+            ColorSpace::Lch => lch_to_rgb(c),
+            ColorSpace::Oklab => oklab_to_rgb(c),
+            ColorSpace::Oklch => oklch_to_rgb(c),
+        }
+    }
+}
+
+impl Ramp {
+    /// Convert the colors of the ramp to `space`.
+    pub fn to_color_space(&self, space: ColorSpace) -> Vec<[f64; 3]> {
+        self.iter().map(|&color| space.from_rgb(color)).collect()
+    }
+
+    /// Create a ramp from colors of `space`.
+    pub fn from_color_space(space: ColorSpace, colors: &[[f64; 3]]) -> Ramp {
+        Ramp::new(colors.iter().map(|&c| space.to_rgb(c)).collect())
+    }
+
+    /// Convert the colors of the ramp to CMYK (see [`rgb_to_cmyk`]).
+    pub fn to_cmyk(&self) -> Vec<[f64; 4]> {
+        self.iter().map(|&color| rgb_to_cmyk(color)).collect()
+    }
+
+    /// Create a ramp from CMYK colors (see [`cmyk_to_rgb`]).
+    pub fn from_cmyk(colors: &[[f64; 4]]) -> Ramp {
+        Ramp::new(colors.iter().map(|&c| cmyk_to_rgb(c)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Palette;
+
+    const SPACES: [ColorSpace; 8] = [
+        ColorSpace::LinearRgb,
+        ColorSpace::Hsl,
+        ColorSpace::Hsv,
+        ColorSpace::Xyz,
+        ColorSpace::Lab,
+        ColorSpace::Lch,
+        ColorSpace::Oklab,
+        ColorSpace::Oklch,
+    ];
+
+    #[test]
+    fn test_round_trips() {
+        for palette in Palette::iter() {
+            for &color in palette.colors(palette.max_classes()).unwrap() {
+                assert_eq!(linear_to_rgb(rgb_to_linear(color)), color);
+                assert_eq!(lab_to_rgb(rgb_to_lab(color)), color);
+                assert_eq!(oklab_to_rgb(rgb_to_oklab(color)), color);
+                assert_eq!(cmyk_to_rgb(rgb_to_cmyk(color)), color);
+                for &space in &SPACES {
+                    assert_eq!(space.to_rgb(space.from_rgb(color)), color, "{:?}", space);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lab_reference_values() {
+        let white = rgb_to_lab(RGB::new(255, 255, 255));
+        assert!((white[0] - 100.).abs() < 1e-3);
+        assert!(white[1].abs() < 1e-3 && white[2].abs() < 1e-3);
+        let red = rgb_to_lab(RGB::new(255, 0, 0));
+        assert!((red[0] - 53.24).abs() < 0.01);
+        assert!((red[1] - 80.09).abs() < 0.01);
+        assert!((red[2] - 67.20).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_hsl_hsv_cmyk_reference_values() {
+        let orange = RGB::new(255, 128, 0);
+        let hsl = rgb_to_hsl(orange);
+        assert!((hsl[0] - 30.1).abs() < 0.1 && hsl[1] == 1. && (hsl[2] - 0.5).abs() < 0.01);
+        let hsv = rgb_to_hsv(orange);
+        assert!((hsv[0] - 30.1).abs() < 0.1 && hsv[1] == 1. && hsv[2] == 1.);
+        assert_eq!(rgb_to_cmyk(RGB::new(0, 0, 0)), [0., 0., 0., 1.]);
+        assert_eq!(rgb_to_cmyk(RGB::new(255, 0, 255)), [0., 1., 0., 0.]);
+        assert_eq!(rgb_to_hsl(RGB::new(128, 128, 128))[1], 0.);
+    }
+
+    #[test]
+    fn test_ramp_conversions() {
+        let ramp = Palette::Spectral.ramp(11).unwrap();
+        for &space in &SPACES {
+            let converted = ramp.to_color_space(space);
+            assert_eq!(Ramp::from_color_space(space, &converted), ramp);
+        }
+        assert_eq!(Ramp::from_cmyk(&ramp.to_cmyk()), ramp);
+    }
+}
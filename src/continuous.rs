@@ -0,0 +1,177 @@
+//! Continuous color maps interpolated between the colors of a palette.
+
+use crate::colorspace::{
+    lab_to_rgb, linear_to_rgb, oklab_to_rgb, rgb_to_lab, rgb_to_linear, rgb_to_oklab, rgb_to_unit,
+    unit_to_rgb,
+};
+use crate::Palette;
+use rgb::RGB;
+
+/// Color space in which the colors of a [`Colormap`] are interpolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Gamma-encoded sRGB components (what most tools do).
+    Srgb,
+    /// Linear light RGB components.
+    LinearRgb,
+    /// CIELAB (D65), perceptually more uniform than RGB.
+    Lab,
+    /// OKLab, perceptually uniform and free of the hue shifts of CIELAB.
+    #[default]
+    Oklab,
+}
+
+impl Interpolation {
+    fn encode(self, color: RGB<u8>) -> [f64; 3] {
+        match self {
+            Interpolation::Srgb => rgb_to_unit(color),
+            Interpolation::LinearRgb => rgb_to_linear(color),
+            Interpolation::Lab => rgb_to_lab(color),
+            Interpolation::Oklab => rgb_to_oklab(color),
+        }
+    }
+
+    fn decode(self, c: [f64; 3]) -> RGB<u8> {
+        match self {
+            Interpolation::Srgb => unit_to_rgb(c),
+            Interpolation::LinearRgb => linear_to_rgb(c),
+            Interpolation::Lab => lab_to_rgb(c),
+            Interpolation::Oklab => oklab_to_rgb(c),
+        }
+    }
+}
+
+/// Continuous color map, interpolating between evenly spaced colors.
+///
+/// ```rust
+/// use colorbrewer::{Colormap, Interpolation, Palette};
+///
+/// let colormap = Colormap::new(Palette::Blues, Interpolation::Lab);
+/// assert_eq!(colormap.eval(0.), rgb::RGB { r: 247, g: 251, b: 255 });
+/// assert_eq!(colormap.eval(1.), rgb::RGB { r: 8, g: 48, b: 107 });
+/// assert_eq!(colormap.sample(25).len(), 25);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Colormap {
+    stops: Vec<[f64; 3]>,
+    interpolation: Interpolation,
+}
+
+impl Colormap {
+    /// Create a color map interpolating between the colors of the
+    /// largest color ramp defined for `palette`.
+    pub fn new(palette: Palette, interpolation: Interpolation) -> Self {
+        let colors = palette
+            .colors(palette.max_classes())
+            .expect("the largest ramp of a palette is always defined");
+        Colormap::from_colors(colors, interpolation)
+    }
+
+    /// Create a color map interpolating between arbitrary colors,
+    /// evenly spaced between `0` and `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn from_colors(colors: &[RGB<u8>], interpolation: Interpolation) -> Self {
+        assert!(!colors.is_empty(), "a colormap needs at least one color");
+        Colormap {
+            stops: colors.iter().map(|&c| interpolation.encode(c)).collect(),
+            interpolation,
+        }
+    }
+
+    /// Color space used for the interpolation.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Reverse the color map, `0` giving the last color and `1` the first one.
+    pub fn reversed(mut self) -> Self {
+        self.stops.reverse();
+        self
+    }
+
+    /// Evaluate the color map at `t`, `0` giving the first color of the ramp
+    /// and `1` the last one. Values outside of `[0, 1]` are clamped and `NaN`
+    /// is treated as `0`.
+    pub fn eval(&self, t: f64) -> RGB<u8> {
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        let last = self.stops.len() - 1;
+        let position = t * last as f64;
+        let index = (position.floor() as usize).min(last);
+        if index == last {
+            return self.interpolation.decode(self.stops[last]);
+        }
+        let frac = position - index as f64;
+        let (a, b) = (self.stops[index], self.stops[index + 1]);
+        self.interpolation.decode([
+            a[0] + (b[0] - a[0]) * frac,
+            a[1] + (b[1] - a[1]) * frac,
+            a[2] + (b[2] - a[2]) * frac,
+        ])
+    }
+
+    /// Sample `n` evenly spaced colors from the color map,
+    /// including both of its ends (when `n` is greater than 1).
+    pub fn sample(&self, n: usize) -> Vec<RGB<u8>> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.eval(0.5)],
+            _ => (0..n)
+                .map(|i| self.eval(i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+impl Palette {
+    /// Create a continuous [`Colormap`] from this palette.
+    pub fn colormap(&self, interpolation: Interpolation) -> Colormap {
+        Colormap::new(*self, interpolation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Colormap, Interpolation, Palette};
+    use rgb::RGB;
+
+    #[test]
+    fn test_eval_hits_palette_colors() {
+        let colors = Palette::RdBu.colors(11).unwrap();
+        for &interpolation in &[
+            Interpolation::Srgb,
+            Interpolation::LinearRgb,
+            Interpolation::Lab,
+            Interpolation::Oklab,
+        ] {
+            let colormap = Colormap::new(Palette::RdBu, interpolation);
+            for (i, &color) in colors.iter().enumerate() {
+                assert_eq!(colormap.eval(i as f64 / 10.), color);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eval_between_colors() {
+        let colors = [RGB::new(0, 0, 0), RGB::new(255, 255, 255)];
+        let srgb = Colormap::from_colors(&colors, Interpolation::Srgb);
+        assert_eq!(srgb.eval(0.5), RGB::new(128, 128, 128));
+        let linear = Colormap::from_colors(&colors, Interpolation::LinearRgb);
+        assert_eq!(linear.eval(0.5), RGB::new(188, 188, 188));
+        assert_eq!(srgb.eval(-1.), colors[0]);
+        assert_eq!(srgb.eval(2.), colors[1]);
+        assert_eq!(srgb.eval(f64::NAN), colors[0]);
+    }
+
+    #[test]
+    fn test_sample() {
+        let colormap = Palette::Greens.colormap(Interpolation::Oklab);
+        let colors = colormap.sample(20);
+        assert_eq!(colors.len(), 20);
+        assert_eq!(colors[0], Palette::Greens.colors(9).unwrap()[0]);
+        assert_eq!(colors[19], Palette::Greens.colors(9).unwrap()[8]);
+        assert!(colormap.sample(0).is_empty());
+    }
+}
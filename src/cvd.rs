@@ -0,0 +1,289 @@
+//! Simulation of color vision deficiencies (CVD), to check that the colors of a ramp
+//! can still be told apart by people with color blindness.
+//!
+//! The simulations are computed in linear RGB. A partial severity is simulated by
+//! interpolating between the original color and the fully simulated one.
+//!
+//! ```rust
+//! use colorbrewer::cvd::{Deficiency, Model, Simulator};
+//! use colorbrewer::Palette;
+//!
+//! let simulator = Simulator::new(Deficiency::Deuteranopia, Model::Machado, 1.);
+//! let simulated = simulator.simulate_all(Palette::RdYlGn.colors(5).unwrap());
+//! assert_eq!(simulated.len(), 5);
+//! assert!(simulator.is_distinguishable(Palette::PuOr.colors(5).unwrap(), 10.));
+//! ```
+
+use crate::colorspace::{linear_to_rgb, rgb_to_linear};
+use crate::metrics::{delta_e, DeltaE};
+use crate::Ramp;
+use rgb::RGB;
+
+/// Type of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protanopia,
+    /// Missing or anomalous medium-wavelength (green) cones.
+    Deuteranopia,
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+/// Model used to simulate a color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Model {
+    /// Machado, Oliveira and Fernandes (2009).
+    #[default]
+    Machado,
+    /// Viénot, Brettel and Mollon (1999). This model is not suited to tritanopia,
+    /// which is simulated with the Brettel model instead.
+    Vienot,
+    /// Brettel, Viénot and Mollon (1997).
+    Brettel,
+}
+
+type Matrix = [[f64; 3]; 3];
+
+const MACHADO_PROTAN: Matrix = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+const MACHADO_DEUTAN: Matrix = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+const MACHADO_TRITAN: Matrix = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+
+const VIENOT_PROTAN: Matrix = [
+    [0.112_38, 0.887_62, 0.],
+    [0.112_38, 0.887_62, 0.],
+    [0.004_01, -0.004_01, 1.],
+];
+const VIENOT_DEUTAN: Matrix = [
+    [0.292_75, 0.707_25, 0.],
+    [0.292_75, 0.707_25, 0.],
+    [-0.022_34, 0.022_34, 1.],
+];
+
+/// Parameters of the Brettel model: the projection matrices of the two half-planes
+/// and the normal of the plane separating them.
+struct BrettelParams {
+    first: Matrix,
+    second: Matrix,
+    separation: [f64; 3],
+}
+
+const BRETTEL_PROTAN: BrettelParams = BrettelParams {
+    first: [
+        [0.149_80, 1.195_48, -0.345_28],
+        [0.107_64, 0.848_64, 0.043_72],
+        [0.003_84, -0.005_40, 1.001_56],
+    ],
+    second: [
+        [0.145_70, 1.161_72, -0.307_42],
+        [0.108_16, 0.852_91, 0.038_92],
+        [0.003_86, -0.005_24, 1.001_39],
+    ],
+    separation: [0.000_48, 0.003_93, -0.004_41],
+};
+const BRETTEL_DEUTAN: BrettelParams = BrettelParams {
+    first: [
+        [0.364_77, 0.863_81, -0.228_58],
+        [0.262_94, 0.642_45, 0.094_62],
+        [-0.020_06, 0.027_28, 0.992_78],
+    ],
+    second: [
+        [0.372_98, 0.881_66, -0.254_64],
+        [0.259_54, 0.635_06, 0.105_40],
+        [-0.019_80, 0.027_84, 0.991_96],
+    ],
+    separation: [-0.002_81, -0.006_11, 0.008_92],
+};
+const BRETTEL_TRITAN: BrettelParams = BrettelParams {
+    first: [
+        [1.012_77, 0.135_48, -0.148_26],
+        [-0.012_43, 0.868_12, 0.144_31],
+        [0.075_89, 0.805_00, 0.119_11],
+    ],
+    second: [
+        [0.936_78, 0.189_79, -0.126_57],
+        [0.061_54, 0.815_26, 0.123_20],
+        [-0.375_62, 1.127_67, 0.247_96],
+    ],
+    separation: [0.039_01, -0.027_88, -0.011_13],
+};
+
+fn apply(m: &Matrix, c: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * c[0] + m[0][1] * c[1] + m[0][2] * c[2],
+        m[1][0] * c[0] + m[1][1] * c[1] + m[1][2] * c[2],
+        m[2][0] * c[0] + m[2][1] * c[1] + m[2][2] * c[2],
+    ]
+}
+
+fn brettel(params: &BrettelParams, c: [f64; 3]) -> [f64; 3] {
+    let n = params.separation;
+    let side = n[0] * c[0] + n[1] * c[1] + n[2] * c[2];
+    if side >= 0. {
+        apply(&params.first, c)
+    } else {
+        apply(&params.second, c)
+    }
+}
+
+/// Simulator of a color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simulator {
+    deficiency: Deficiency,
+    model: Model,
+    severity: f64,
+}
+
+impl Simulator {
+    /// Create a simulator of `deficiency` using `model`. The `severity` goes from `0`
+    /// (normal vision) to `1` (dichromacy) and is clamped to this range.
+    pub fn new(deficiency: Deficiency, model: Model, severity: f64) -> Self {
+        let severity = if severity.is_nan() {
+            1.
+        } else {
+            severity.clamp(0., 1.)
+        };
+        Simulator {
+            deficiency,
+            model,
+            severity,
+        }
+    }
+
+    /// The simulated deficiency.
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// The severity of the simulated deficiency, between `0` and `1`.
+    pub fn severity(&self) -> f64 {
+        self.severity
+    }
+
+    /// Simulate how `color` is perceived.
+    pub fn simulate(&self, color: RGB<u8>) -> RGB<u8> {
+        let c = rgb_to_linear(color);
+        let simulated = match (self.model, self.deficiency) {
+            (Model::Machado, Deficiency::Protanopia) => apply(&MACHADO_PROTAN, c),
+            (Model::Machado, Deficiency::Deuteranopia) => apply(&MACHADO_DEUTAN, c),
+            (Model::Machado, Deficiency::Tritanopia) => apply(&MACHADO_TRITAN, c),
+            (Model::Vienot, Deficiency::Protanopia) => apply(&VIENOT_PROTAN, c),
+            (Model::Vienot, Deficiency::Deuteranopia) => apply(&VIENOT_DEUTAN, c),
+            (Model::Brettel, Deficiency::Protanopia) => brettel(&BRETTEL_PROTAN, c),
+            (Model::Brettel, Deficiency::Deuteranopia) => brettel(&BRETTEL_DEUTAN, c),
+            (_, Deficiency::Tritanopia) => brettel(&BRETTEL_TRITAN, c),
+        };
+        let s = self.severity;
+        linear_to_rgb([
+            c[0] + (simulated[0] - c[0]) * s,
+            c[1] + (simulated[1] - c[1]) * s,
+            c[2] + (simulated[2] - c[2]) * s,
+        ])
+    }
+
+    /// Simulate how each of the `colors` is perceived.
+    pub fn simulate_all(&self, colors: &[RGB<u8>]) -> Vec<RGB<u8>> {
+        colors.iter().map(|&color| self.simulate(color)).collect()
+    }
+
+    /// Smallest CIELAB distance (CIE76 ΔE) between two of the simulated `colors`,
+    /// or `f64::INFINITY` if there are less than two colors.
+    pub fn min_delta_e(&self, colors: &[RGB<u8>]) -> f64 {
+        let simulated = self.simulate_all(colors);
+        let mut min = f64::INFINITY;
+        for (i, &a) in simulated.iter().enumerate() {
+            for &b in &simulated[i + 1..] {
+                min = min.min(delta_e(a, b, DeltaE::Cie76));
+            }
+        }
+        min
+    }
+
+    /// Whether all the simulated `colors` are at least `min_delta_e` apart
+    /// (CIE76 ΔE, a distance of about 2.3 being just noticeable).
+    pub fn is_distinguishable(&self, colors: &[RGB<u8>], min_delta_e: f64) -> bool {
+        self.min_delta_e(colors) >= min_delta_e
+    }
+}
+
+impl Ramp {
+    /// Simulate how the colors of the ramp are perceived with a color vision deficiency.
+    pub fn simulate_cvd(&self, simulator: &Simulator) -> Ramp {
+        Ramp::new(simulator.simulate_all(self.colors()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Palette;
+
+    const ALL: [(Deficiency, Model); 9] = [
+        (Deficiency::Protanopia, Model::Machado),
+        (Deficiency::Deuteranopia, Model::Machado),
+        (Deficiency::Tritanopia, Model::Machado),
+        (Deficiency::Protanopia, Model::Vienot),
+        (Deficiency::Deuteranopia, Model::Vienot),
+        (Deficiency::Tritanopia, Model::Vienot),
+        (Deficiency::Protanopia, Model::Brettel),
+        (Deficiency::Deuteranopia, Model::Brettel),
+        (Deficiency::Tritanopia, Model::Brettel),
+    ];
+
+    #[test]
+    fn test_greys_are_preserved() {
+        for &(deficiency, model) in &ALL {
+            let simulator = Simulator::new(deficiency, model, 1.);
+            for &grey in Palette::Greys.colors(9).unwrap() {
+                let simulated = simulator.simulate(grey);
+                for (a, b) in [
+                    (grey.r, simulated.r),
+                    (grey.g, simulated.g),
+                    (grey.b, simulated.b),
+                ] {
+                    assert!((i16::from(a) - i16::from(b)).abs() <= 1, "{:?}", simulator);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_severity() {
+        let red = RGB::new(228, 26, 28);
+        let none = Simulator::new(Deficiency::Protanopia, Model::Machado, 0.);
+        assert_eq!(none.simulate(red), red);
+        let full = Simulator::new(Deficiency::Protanopia, Model::Machado, 1.);
+        let half = Simulator::new(Deficiency::Protanopia, Model::Machado, 0.5);
+        assert_ne!(full.simulate(red), red);
+        assert_ne!(half.simulate(red), full.simulate(red));
+        assert_eq!(
+            Simulator::new(Deficiency::Tritanopia, Model::Brettel, 3.).severity(),
+            1.
+        );
+    }
+
+    #[test]
+    fn test_distinguishable() {
+        // Red and green are confused by deuteranopes, not by tritanopes.
+        let colors = [RGB::new(228, 26, 28), RGB::new(77, 175, 74)];
+        let deutan = Simulator::new(Deficiency::Deuteranopia, Model::Machado, 1.);
+        let tritan = Simulator::new(Deficiency::Tritanopia, Model::Machado, 1.);
+        assert!(deutan.min_delta_e(&colors) < tritan.min_delta_e(&colors));
+        let ramp = Palette::Blues.ramp(5).unwrap();
+        assert!(deutan.is_distinguishable(&ramp, 5.));
+        assert_eq!(ramp.simulate_cvd(&deutan).len(), 5);
+        assert_eq!(deutan.min_delta_e(&colors[..1]), f64::INFINITY);
+    }
+}
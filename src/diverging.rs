@@ -0,0 +1,221 @@
+//! Diverging scales, pinning the central color of a palette to a midpoint value.
+
+use crate::classifier::DEFAULT_NO_DATA;
+use crate::{Colormap, Error, Interpolation, Palette};
+use rgb::RGB;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Colors {
+    Discrete(Vec<RGB<u8>>),
+    Continuous(Colormap),
+}
+
+/// Scale mapping values to the colors of a (diverging) palette, so that the central
+/// color of the palette lands at a given midpoint value, even when the range of the
+/// values is asymmetric around it.
+///
+/// Each side of the midpoint is mapped linearly to its half of the palette:
+/// `min` gets the first color, `mid` the central color and `max` the last color.
+/// Values outside of `[min, max]` are clamped and `NaN` gets the no data color.
+///
+/// ```rust
+/// use colorbrewer::{DivergingScale, Interpolation, Palette};
+///
+/// // Temperature anomalies, from -2 to +6 degrees:
+/// let scale = DivergingScale::discrete(Palette::RdBu, 5, -2., 0., 6.).unwrap().reversed();
+/// let colors = Palette::RdBu.colors(5).unwrap();
+/// assert_eq!(scale.color_for(0.), colors[2]);
+/// assert_eq!(scale.color_for(-2.), colors[4]);
+/// assert_eq!(scale.color_for(6.), colors[0]);
+///
+/// let scale = DivergingScale::continuous(Palette::RdBu, Interpolation::Lab, -2., 0., 6.).unwrap();
+/// assert_eq!(scale.color_for(0.), Palette::RdBu.colors(11).unwrap()[5]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DivergingScale {
+    min: f64,
+    mid: f64,
+    max: f64,
+    colors: Colors,
+    no_data: RGB<u8>,
+}
+
+impl DivergingScale {
+    /// Create a scale using the `nb_class` colors of the ColorBrewer ramp of `palette`.
+    /// With an odd number of classes the midpoint falls in the middle of the central
+    /// class, with an even number it is the break between the two central classes.
+    ///
+    /// Return an error if the palette doesn't define this number of colors
+    /// or if `min < mid < max` doesn't hold.
+    pub fn discrete(
+        palette: Palette,
+        nb_class: u32,
+        min: f64,
+        mid: f64,
+        max: f64,
+    ) -> Result<Self, Error> {
+        let colors = palette.try_colors(nb_class)?.to_vec();
+        DivergingScale::with_colors(Colors::Discrete(colors), min, mid, max)
+    }
+
+    /// Create a scale interpolating continuously between the colors of the
+    /// largest ramp of `palette`.
+    ///
+    /// Return an error if `min < mid < max` doesn't hold.
+    pub fn continuous(
+        palette: Palette,
+        interpolation: Interpolation,
+        min: f64,
+        mid: f64,
+        max: f64,
+    ) -> Result<Self, Error> {
+        let colormap = Colormap::new(palette, interpolation);
+        DivergingScale::with_colors(Colors::Continuous(colormap), min, mid, max)
+    }
+
+    fn with_colors(colors: Colors, min: f64, mid: f64, max: f64) -> Result<Self, Error> {
+        if !(min < mid && mid < max && min.is_finite() && max.is_finite()) {
+            return Err(Error::InvalidClassification(
+                "a diverging scale needs finite values with min < mid < max",
+            ));
+        }
+        Ok(DivergingScale {
+            min,
+            mid,
+            max,
+            colors,
+            no_data: DEFAULT_NO_DATA,
+        })
+    }
+
+    /// Reverse the order of the colors of the palette.
+    pub fn reversed(mut self) -> Self {
+        self.colors = match self.colors {
+            Colors::Discrete(mut colors) => {
+                colors.reverse();
+                Colors::Discrete(colors)
+            }
+            Colors::Continuous(colormap) => Colors::Continuous(colormap.reversed()),
+        };
+        self
+    }
+
+    /// Set the color of the missing values (`NaN`), `#cccccc` by default.
+    pub fn no_data_color(mut self, color: RGB<u8>) -> Self {
+        self.no_data = color;
+        self
+    }
+
+    /// Position of `value` on the palette, between `0` (at `min`) and `1` (at `max`),
+    /// `mid` being at `0.5`. Return `NaN` for `NaN`.
+    pub fn normalize(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            f64::NAN
+        } else if value <= self.mid {
+            (0.5 * (value - self.min) / (self.mid - self.min)).max(0.)
+        } else {
+            (0.5 + 0.5 * (value - self.mid) / (self.max - self.mid)).min(1.)
+        }
+    }
+
+    /// Class breaks of a discrete scale, in the unit of the values
+    /// (`None` for a continuous scale).
+    pub fn breaks(&self) -> Option<Vec<f64>> {
+        match &self.colors {
+            Colors::Discrete(colors) => {
+                let n = colors.len() as f64;
+                Some(
+                    (0..=colors.len())
+                        .map(|i| self.denormalize(i as f64 / n))
+                        .collect(),
+                )
+            }
+            Colors::Continuous(_) => None,
+        }
+    }
+
+    fn denormalize(&self, t: f64) -> f64 {
+        if t <= 0.5 {
+            self.min + (self.mid - self.min) * t * 2.
+        } else {
+            self.mid + (self.max - self.mid) * (t - 0.5) * 2.
+        }
+    }
+
+    /// Color of `value`.
+    pub fn color_for(&self, value: f64) -> RGB<u8> {
+        let t = self.normalize(value);
+        if t.is_nan() {
+            return self.no_data;
+        }
+        match &self.colors {
+            Colors::Discrete(colors) => {
+                let class = (t * colors.len() as f64).floor() as usize;
+                colors[class.min(colors.len() - 1)]
+            }
+            Colors::Continuous(colormap) => colormap.eval(t),
+        }
+    }
+
+    /// Colors of each of the `values`.
+    pub fn colors_for(&self, values: &[f64]) -> Vec<RGB<u8>> {
+        values.iter().map(|&value| self.color_for(value)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DivergingScale, Interpolation, Palette};
+
+    #[test]
+    fn test_discrete_odd() {
+        let colors = Palette::PuOr.colors(5).unwrap();
+        let scale = DivergingScale::discrete(Palette::PuOr, 5, -10., 0., 40.).unwrap();
+        assert_eq!(scale.color_for(-10.), colors[0]);
+        assert_eq!(scale.color_for(-5.), colors[1]);
+        assert_eq!(scale.color_for(-1.), colors[2]);
+        assert_eq!(scale.color_for(0.), colors[2]);
+        assert_eq!(scale.color_for(3.), colors[2]);
+        assert_eq!(scale.color_for(20.), colors[3]);
+        assert_eq!(scale.color_for(40.), colors[4]);
+        assert_eq!(scale.color_for(100.), colors[4]);
+        let breaks = scale.breaks().unwrap();
+        let expected = [-10., -6., -2., 8., 24., 40.];
+        assert_eq!(breaks.len(), expected.len());
+        for (b, e) in breaks.iter().zip(expected.iter()) {
+            assert!((b - e).abs() < 1e-9, "{} != {}", b, e);
+        }
+    }
+
+    #[test]
+    fn test_discrete_even() {
+        let colors = Palette::BrBG.colors(4).unwrap();
+        let scale = DivergingScale::discrete(Palette::BrBG, 4, 0., 100., 120.).unwrap();
+        assert_eq!(scale.color_for(99.), colors[1]);
+        assert_eq!(scale.color_for(100.), colors[2]);
+        assert_eq!(scale.breaks().unwrap(), vec![0., 50., 100., 110., 120.]);
+    }
+
+    #[test]
+    fn test_continuous() {
+        let colors = Palette::Spectral.colors(11).unwrap();
+        let scale =
+            DivergingScale::continuous(Palette::Spectral, Interpolation::Oklab, -1., 0., 9.)
+                .unwrap();
+        assert_eq!(scale.normalize(-0.5), 0.25);
+        assert_eq!(scale.normalize(4.5), 0.75);
+        assert_eq!(scale.color_for(-1.), colors[0]);
+        assert_eq!(scale.color_for(0.), colors[5]);
+        assert_eq!(scale.color_for(9.), colors[10]);
+        assert_eq!(scale.reversed().color_for(9.), colors[0]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(DivergingScale::discrete(Palette::RdBu, 5, 0., 0., 1.).is_err());
+        assert!(DivergingScale::discrete(Palette::RdBu, 5, 0., 2., 1.).is_err());
+        assert!(DivergingScale::discrete(Palette::RdBu, 12, -1., 0., 1.).is_err());
+        let scale = DivergingScale::discrete(Palette::RdBu, 5, -1., 0., 1.).unwrap();
+        assert_eq!(scale.color_for(f64::NAN), rgb::RGB::new(204, 204, 204));
+    }
+}
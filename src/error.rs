@@ -0,0 +1,168 @@
+//! Error type of the crate.
+
+use crate::Palette;
+use std::fmt;
+
+/// Errors returned when looking up a palette or one of its color ramps,
+/// when parsing a color, when classifying values or when reading a palette file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No palette has this name.
+    UnknownPalette {
+        /// The name that was looked up.
+        name: String,
+        /// The palette with the closest name, if any is close enough.
+        suggestion: Option<Palette>,
+    },
+    /// The palette doesn't define a color ramp with this number of colors.
+    InvalidClassCount {
+        /// The palette that was looked up.
+        palette: Palette,
+        /// The requested number of colors.
+        nb_value: u32,
+        /// Minimum number of colors defined for this palette.
+        min: u32,
+        /// Maximum number of colors defined for this palette.
+        max: u32,
+    },
+    /// The string isn't a valid hexadecimal color.
+    InvalidColor(String),
+    /// The values can't be classified as requested.
+    InvalidClassification(&'static str),
+    /// The content of a palette file can't be read.
+    InvalidPaletteFile(&'static str),
+}
+
+impl Error {
+    pub(crate) fn unknown_palette(name: &str) -> Self {
+        Error::UnknownPalette {
+            name: name.to_owned(),
+            suggestion: closest_palette(name),
+        }
+    }
+
+    pub(crate) fn invalid_class_count(palette: Palette, nb_value: u32) -> Self {
+        Error::InvalidClassCount {
+            palette,
+            nb_value,
+            min: palette.min_classes(),
+            max: palette.max_classes(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownPalette {
+                name,
+                suggestion: Some(suggestion),
+            } => write!(
+                f,
+                "unknown palette \"{}\" (did you mean \"{}\"?)",
+                name, suggestion
+            ),
+            Error::UnknownPalette {
+                name,
+                suggestion: None,
+            } => write!(f, "unknown palette \"{}\"", name),
+            Error::InvalidClassCount {
+                palette,
+                nb_value,
+                min,
+                max,
+            } => write!(
+                f,
+                "palette {} has no color ramp with {} colors (expected {} to {} colors)",
+                palette, nb_value, min, max
+            ),
+            Error::InvalidColor(s) => write!(f, "invalid hexadecimal color \"{}\"", s),
+            Error::InvalidClassification(reason) => write!(f, "invalid classification: {}", reason),
+            Error::InvalidPaletteFile(reason) => write!(f, "invalid palette file: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Find the palette whose name is the closest to `name`, ignoring case,
+/// if it is close enough to be a plausible typo.
+fn closest_palette(name: &str) -> Option<Palette> {
+    let name = name.to_lowercase();
+    Palette::iter()
+        .map(|palette| {
+            let candidate = palette.name().to_lowercase();
+            (levenshtein(&name, &candidate), palette)
+        })
+        .filter(|&(distance, _)| distance <= 2.max(name.chars().count() / 3))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, palette)| palette)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::levenshtein;
+    use crate::{try_get_color_ramp, Error, Palette};
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("RdBu", "RdBu"), 0);
+    }
+
+    #[test]
+    fn test_unknown_palette() {
+        let err = "Spectrl".parse::<Palette>().unwrap_err();
+        assert_eq!(
+            err,
+            Error::UnknownPalette {
+                name: "Spectrl".to_owned(),
+                suggestion: Some(Palette::Spectral),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unknown palette \"Spectrl\" (did you mean \"Spectral\"?)"
+        );
+        let err = "Viridis".parse::<Palette>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown palette \"Viridis\"");
+    }
+
+    #[test]
+    fn test_invalid_class_count() {
+        let err = try_get_color_ramp(Palette::Accent, 9).unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidClassCount {
+                palette: Palette::Accent,
+                nb_value: 9,
+                min: 3,
+                max: 8,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "palette Accent has no color ramp with 9 colors (expected 3 to 8 colors)"
+        );
+        assert_eq!(
+            try_get_color_ramp(Palette::Accent, 8).ok(),
+            Palette::Accent.colors(8).map(|colors| colors.to_vec())
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Export of the palettes to the file formats of other tools.
+//!
+//! Each submodule targets one tool and returns the content of the file, as a `String`
+//! for the text formats or as bytes for the binary ones. The modules of the swatch
+//! formats ([`aco`], [`ase`], [`cpt`] and [`gpl`]) can also read these files back.
+
+pub mod aco;
+pub mod ase;
+pub mod cpt;
+pub mod gpl;
+pub mod mapbox;
+pub mod qgis;
+pub mod sld;
+
+use crate::{Error, Palette, Ramp};
+
+/// A color ramp with a name, as stored in swatch files.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NamedRamp {
+    /// Name of the ramp.
+    pub name: String,
+    /// Colors of the ramp.
+    pub ramp: Ramp,
+}
+
+/// Every color ramp of every palette, named like `Blues_5`.
+pub(crate) fn library_ramps() -> impl Iterator<Item = NamedRamp> {
+    Palette::iter().flat_map(|palette| {
+        (palette.min_classes()..=palette.max_classes()).map(move |nb_value| NamedRamp {
+            name: format!("{}_{}", palette, nb_value),
+            ramp: palette.ramp(nb_value).unwrap(),
+        })
+    })
+}
+
+/// Escape the characters that can't appear as is in an XML attribute value.
+pub(crate) fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Null-terminated big-endian UTF-16 string, used by the Adobe formats.
+pub(crate) fn utf16_be(s: &str) -> Vec<u8> {
+    s.encode_utf16()
+        .chain(std::iter::once(0))
+        .flat_map(u16::to_be_bytes)
+        .collect()
+}
+
+/// Cursor reading big-endian values from the content of a binary file.
+pub(crate) struct BigEndianReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BigEndianReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        BigEndianReader { data }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.data.len() < len {
+            return Err(Error::InvalidPaletteFile("unexpected end of file"));
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn f32(&mut self) -> Result<f32, Error> {
+        self.u32().map(f32::from_bits)
+    }
+
+    /// Read a UTF-16 string of `len` code units, the last one being a null terminator.
+    pub(crate) fn utf16(&mut self, len: usize) -> Result<String, Error> {
+        let units: Vec<u16> = self
+            .bytes(2 * len)?
+            .chunks(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .take_while(|&unit| unit != 0)
+            .collect();
+        String::from_utf16(&units).map_err(|_| Error::InvalidPaletteFile("invalid UTF-16 name"))
+    }
+}
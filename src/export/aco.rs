@@ -0,0 +1,140 @@
+//! Photoshop color swatches (`.aco`).
+//!
+//! The files are written with both the version 1 section and the version 2 one,
+//! which adds the names of the swatches. An `.aco` file has no groups, so the
+//! library contains a flat list of swatches named like `Blues_5 1`.
+//!
+//! ```rust
+//! use colorbrewer::export::aco;
+//! use colorbrewer::Palette;
+//!
+//! let content = aco::write_palette(Palette::Dark2, 3).unwrap();
+//! assert_eq!(aco::read(&content).unwrap(), Palette::Dark2.ramp(3).unwrap());
+//! ```
+
+use crate::export::{library_ramps, utf16_be, BigEndianReader};
+use crate::format::to_hex;
+use crate::{Error, Palette, Ramp};
+use rgb::RGB;
+use std::convert::TryFrom;
+
+const RGB_SPACE: u16 = 0;
+
+fn write_named(swatches: &[(String, RGB<u8>)]) -> Result<Vec<u8>, Error> {
+    let nb_swatch = u16::try_from(swatches.len())
+        .map_err(|_| Error::InvalidPaletteFile("more than 65535 swatches"))?;
+    let mut out = Vec::new();
+    for version in 1..=2u16 {
+        out.extend_from_slice(&version.to_be_bytes());
+        out.extend_from_slice(&nb_swatch.to_be_bytes());
+        for (name, color) in swatches {
+            out.extend_from_slice(&RGB_SPACE.to_be_bytes());
+            for component in &[color.r, color.g, color.b, 0] {
+                out.extend_from_slice(&(u16::from(*component) * 257).to_be_bytes());
+            }
+            if version == 2 {
+                // The length is in UTF-16 code units, including the null terminator
+                // which ends the bytes returned by `utf16_be`.
+                let name = utf16_be(name);
+                let name_len = u32::try_from(name.len() / 2)
+                    .map_err(|_| Error::InvalidPaletteFile("swatch name too long"))?;
+                out.extend_from_slice(&name_len.to_be_bytes());
+                out.extend_from_slice(&name);
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Swatches file containing `colors`, each color being named after its hexadecimal code.
+///
+/// Return an error if there are more than 65535 colors, the maximum of the format.
+pub fn write(colors: &[RGB<u8>]) -> Result<Vec<u8>, Error> {
+    let swatches: Vec<(String, RGB<u8>)> = colors.iter().map(|&c| (to_hex(c), c)).collect();
+    write_named(&swatches)
+}
+
+/// Swatches file containing the ramp of `palette` with `nb_value` colors.
+///
+/// Return an error if the palette doesn't define this number of colors.
+pub fn write_palette(palette: Palette, nb_value: u32) -> Result<Vec<u8>, Error> {
+    write(palette.try_colors(nb_value)?)
+}
+
+/// Swatches file containing every color ramp of every palette,
+/// each color being named like `Blues_5 1`.
+pub fn library() -> Vec<u8> {
+    let swatches: Vec<(String, RGB<u8>)> = library_ramps()
+        .flat_map(|named| {
+            let name = named.name;
+            named
+                .ramp
+                .into_colors()
+                .into_iter()
+                .enumerate()
+                .map(move |(i, color)| (format!("{} {}", name, i + 1), color))
+        })
+        .collect();
+    write_named(&swatches).expect("the library has less than 65536 colors")
+}
+
+/// Read the colors of a swatches file (the version 2 section, if any, is used).
+/// Only RGB colors are supported.
+pub fn read(content: &[u8]) -> Result<Ramp, Error> {
+    let mut reader = BigEndianReader::new(content);
+    let mut colors = Vec::new();
+    while !reader.is_empty() {
+        let version = reader.u16()?;
+        if version != 1 && version != 2 {
+            return Err(Error::InvalidPaletteFile("unknown version"));
+        }
+        let count = reader.u16()?;
+        colors.clear();
+        for _ in 0..count {
+            if reader.u16()? != RGB_SPACE {
+                return Err(Error::InvalidPaletteFile("unsupported color space"));
+            }
+            let component = |v: u16| (f64::from(v) / 257.).round() as u8;
+            colors.push(RGB {
+                r: component(reader.u16()?),
+                g: component(reader.u16()?),
+                b: component(reader.u16()?),
+            });
+            reader.u16()?;
+            if version == 2 {
+                let name_len = reader.u32()?;
+                reader.utf16(name_len as usize)?;
+            }
+        }
+    }
+    Ok(Ramp::new(colors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let content = write(&[RGB::new(255, 1, 0)]).unwrap();
+        let expected: &[u8] = &[
+            0, 1, 0, 1, 0, 0, 0xff, 0xff, 1, 1, 0, 0, 0, 0, // version 1
+            0, 2, 0, 1, 0, 0, 0xff, 0xff, 1, 1, 0, 0, 0, 0, // version 2
+            0, 0, 0, 8, 0, b'#', 0, b'f', 0, b'f', 0, b'0', 0, b'1', 0, b'0', 0, b'0', 0, 0,
+        ];
+        assert_eq!(content, expected);
+        assert!(write(&vec![RGB::new(0, 0, 0); 65535]).is_ok());
+        assert!(write(&vec![RGB::new(0, 0, 0); 65536]).is_err());
+    }
+
+    #[test]
+    fn test_read() {
+        // Version 1 only.
+        let content = [0, 1, 0, 1, 0, 0, 0x80, 0x80, 0, 0, 0xff, 0xff, 0, 0];
+        assert_eq!(read(&content).unwrap().colors(), &[RGB::new(128, 0, 255)]);
+        let library = read(&library()).unwrap();
+        assert_eq!(&library[..3], Palette::YlGn.colors(3).unwrap());
+        assert!(read(&[0, 1, 0, 1, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(read(&[0, 1, 0, 2, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Adobe Swatch Exchange files (`.ase`), read by Illustrator, InDesign and Photoshop.
+//!
+//! Each color ramp is stored as a group of swatches named after their hexadecimal code.
+//!
+//! ```rust
+//! use colorbrewer::export::ase;
+//! use colorbrewer::Palette;
+//!
+//! let content = ase::write_palette(Palette::RdBu, 5).unwrap();
+//! assert_eq!(&content[..4], b"ASEF");
+//! let groups = ase::read(&content).unwrap();
+//! assert_eq!(groups[0].name, "RdBu_5");
+//! assert_eq!(groups[0].ramp, Palette::RdBu.ramp(5).unwrap());
+//! ```
+
+use crate::colorspace::cmyk_to_rgb;
+use crate::export::{library_ramps, utf16_be, BigEndianReader, NamedRamp};
+use crate::format::to_hex;
+use crate::{Error, Palette, Ramp};
+use rgb::RGB;
+use std::convert::TryFrom;
+
+const GROUP_START: u16 = 0xc001;
+const GROUP_END: u16 = 0xc002;
+const COLOR: u16 = 0x0001;
+/// Type of the swatches, neither global nor spot colors.
+const NORMAL_COLOR: u16 = 2;
+
+fn push_block(out: &mut Vec<u8>, block_type: u16, content: &[u8]) -> Result<(), Error> {
+    let len = u32::try_from(content.len())
+        .map_err(|_| Error::InvalidPaletteFile("block larger than 4 GiB"))?;
+    out.extend_from_slice(&block_type.to_be_bytes());
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(content);
+    Ok(())
+}
+
+/// Name of a block, preceded by its length in UTF-16 code units.
+fn block_name(name: &str) -> Result<Vec<u8>, Error> {
+    // `utf16_be` ends the name with a null terminator, which is counted in the length.
+    let name = utf16_be(name);
+    let len = u16::try_from(name.len() / 2)
+        .map_err(|_| Error::InvalidPaletteFile("name longer than 65534 UTF-16 code units"))?;
+    let mut content = len.to_be_bytes().to_vec();
+    content.extend_from_slice(&name);
+    Ok(content)
+}
+
+/// Swatch exchange file containing a group of swatches for each of the `ramps`.
+///
+/// Return an error if a name is longer than 65534 UTF-16 code units, the maximum
+/// of the format.
+pub fn write(ramps: &[NamedRamp]) -> Result<Vec<u8>, Error> {
+    let nb_block: usize = ramps.iter().map(|r| r.ramp.len() + 2).sum();
+    let nb_block =
+        u32::try_from(nb_block).map_err(|_| Error::InvalidPaletteFile("too many swatches"))?;
+    let mut out = b"ASEF".to_vec();
+    out.extend_from_slice(&[0, 1, 0, 0]);
+    out.extend_from_slice(&nb_block.to_be_bytes());
+    for NamedRamp { name, ramp } in ramps {
+        push_block(&mut out, GROUP_START, &block_name(name)?)?;
+        for &color in ramp {
+            let mut content = block_name(&to_hex(color))?;
+            content.extend_from_slice(b"RGB ");
+            for component in &[color.r, color.g, color.b] {
+                let value = f32::from(*component) / 255.;
+                content.extend_from_slice(&value.to_be_bytes());
+            }
+            content.extend_from_slice(&NORMAL_COLOR.to_be_bytes());
+            push_block(&mut out, COLOR, &content)?;
+        }
+        push_block(&mut out, GROUP_END, &[])?;
+    }
+    Ok(out)
+}
+
+/// Swatch exchange file containing the ramp of `palette` with `nb_value` colors,
+/// as a group named like `Blues_5`.
+///
+/// Return an error if the palette doesn't define this number of colors.
+pub fn write_palette(palette: Palette, nb_value: u32) -> Result<Vec<u8>, Error> {
+    let ramp = NamedRamp {
+        name: format!("{}_{}", palette, nb_value),
+        ramp: palette.try_colors(nb_value)?.into(),
+    };
+    write(&[ramp])
+}
+
+/// Swatch exchange file containing every color ramp of every palette,
+/// each one as a group named like `Blues_5`.
+pub fn library() -> Vec<u8> {
+    write(&library_ramps().collect::<Vec<_>>()).expect("the names of the library are short")
+}
+
+fn read_color(content: &[u8]) -> Result<RGB<u8>, Error> {
+    let mut reader = BigEndianReader::new(content);
+    let name_len = reader.u16()?;
+    reader.utf16(usize::from(name_len))?;
+    let quantize = |v: f32| (v.clamp(0., 1.) * 255.).round() as u8;
+    match reader.bytes(4)? {
+        b"RGB " => Ok(RGB {
+            r: quantize(reader.f32()?),
+            g: quantize(reader.f32()?),
+            b: quantize(reader.f32()?),
+        }),
+        b"Gray" => {
+            let value = quantize(reader.f32()?);
+            Ok(RGB::new(value, value, value))
+        }
+        b"CMYK" => {
+            let mut cmyk = [0.; 4];
+            for c in &mut cmyk {
+                *c = f64::from(reader.f32()?);
+            }
+            Ok(cmyk_to_rgb(cmyk))
+        }
+        _ => Err(Error::InvalidPaletteFile("unsupported color model")),
+    }
+}
+
+/// Read the groups of swatches of a swatch exchange file. The swatches that don't
+/// belong to a group are returned in an additional ramp with an empty name.
+/// CMYK colors are converted with [`cmyk_to_rgb`], LAB colors are not supported.
+pub fn read(content: &[u8]) -> Result<Vec<NamedRamp>, Error> {
+    let mut reader = BigEndianReader::new(content);
+    if reader.bytes(4)? != b"ASEF" {
+        return Err(Error::InvalidPaletteFile("missing \"ASEF\" signature"));
+    }
+    reader.bytes(4)?;
+    let nb_block = reader.u32()?;
+    let mut groups = Vec::new();
+    let mut ungrouped = Vec::new();
+    let mut group: Option<(String, Vec<RGB<u8>>)> = None;
+    for _ in 0..nb_block {
+        let block_type = reader.u16()?;
+        let len = reader.u32()? as usize;
+        let block = reader.bytes(len)?;
+        match block_type {
+            GROUP_START => {
+                let mut block = BigEndianReader::new(block);
+                let name_len = block.u16()?;
+                group = Some((block.utf16(usize::from(name_len))?, Vec::new()));
+            }
+            GROUP_END => {
+                if let Some((name, colors)) = group.take() {
+                    groups.push(NamedRamp {
+                        name,
+                        ramp: Ramp::new(colors),
+                    });
+                }
+            }
+            COLOR => {
+                let color = read_color(block)?;
+                match &mut group {
+                    Some((_, colors)) => colors.push(color),
+                    None => ungrouped.push(color),
+                }
+            }
+            _ => return Err(Error::InvalidPaletteFile("unknown block type")),
+        }
+    }
+    if !ungrouped.is_empty() {
+        groups.push(NamedRamp {
+            name: String::new(),
+            ramp: Ramp::new(ungrouped),
+        });
+    }
+    Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let content = write(&[NamedRamp {
+            name: "A".to_owned(),
+            ramp: Ramp::new(vec![RGB::new(255, 0, 0)]),
+        }])
+        .unwrap();
+        let expected: &[u8] = &[
+            b'A', b'S', b'E', b'F', 0, 1, 0, 0, 0, 0, 0, 3, // header, 3 blocks
+            0xc0, 0x01, 0, 0, 0, 6, 0, 2, 0, b'A', 0, 0, // group start "A"
+            0, 1, 0, 0, 0, 36, 0, 8, 0, b'#', 0, b'f', 0, b'f', 0, b'0', 0, b'0', 0, b'0', 0, b'0',
+            0, 0, b'R', b'G', b'B', b' ', 0x3f, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2, // color "#ff0000"
+            0xc0, 0x02, 0, 0, 0, 0, // group end
+        ];
+        assert_eq!(content, expected);
+    }
+
+    #[test]
+    fn test_name_too_long() {
+        let ramp = NamedRamp {
+            name: "a".repeat(65534),
+            ramp: Ramp::new(vec![RGB::new(255, 0, 0)]),
+        };
+        assert!(write(std::slice::from_ref(&ramp)).is_ok());
+        let ramp = NamedRamp {
+            name: "a".repeat(65535),
+            ..ramp
+        };
+        assert!(write(&[ramp]).is_err());
+    }
+
+    #[test]
+    fn test_library_round_trip() {
+        let groups = read(&library()).unwrap();
+        assert_eq!(groups, library_ramps().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        assert!(read(b"ASEX\0\x01\0\0\0\0\0\0").is_err());
+        let mut content = write_palette(Palette::Set1, 3).unwrap();
+        content.truncate(content.len() - 10);
+        assert!(read(&content).is_err());
+    }
+}
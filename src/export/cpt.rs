@@ -0,0 +1,160 @@
+//! GMT color palette tables (`.cpt`), with one slice of constant color per class.
+//!
+//! ```rust
+//! use colorbrewer::export::cpt;
+//! use colorbrewer::Palette;
+//!
+//! let content = cpt::write_palette(Palette::BrBG, 4, Some(&[-2., -1., 0., 1., 2.])).unwrap();
+//! assert!(content.contains("-2\t166/97/26\t-1\t166/97/26\n"));
+//! let classifier = cpt::read(&content).unwrap();
+//! assert_eq!(classifier.breaks(), &[-2., -1., 0., 1., 2.]);
+//! ```
+
+use crate::export::{library_ramps, NamedRamp};
+use crate::format::parse_hex;
+use crate::{Classifier, Error, Palette};
+use rgb::RGB;
+use std::fmt::Write;
+
+fn gmt_color(color: RGB<u8>) -> String {
+    format!("{}/{}/{}", color.r, color.g, color.b)
+}
+
+/// Color palette table named `name` with the colors and breaks of `classifier`.
+/// The colors of the first and last classes are also used as background and
+/// foreground colors, for the values outside of the range of the breaks.
+pub fn write(name: &str, classifier: &Classifier) -> String {
+    let (colors, breaks) = (classifier.colors(), classifier.breaks());
+    let mut out = String::new();
+    writeln!(out, "# {}", name.replace(['\r', '\n'], " ")).unwrap();
+    out.push_str("# COLOR_MODEL = RGB\n");
+    for (&color, bounds) in colors.iter().zip(breaks.windows(2)) {
+        let color = gmt_color(color);
+        writeln!(out, "{}\t{}\t{}\t{}", bounds[0], color, bounds[1], color).unwrap();
+    }
+    writeln!(out, "B\t{}", gmt_color(colors[0])).unwrap();
+    writeln!(out, "F\t{}", gmt_color(colors[colors.len() - 1])).unwrap();
+    out
+}
+
+/// Color palette table with the ramp of `palette` with `nb_class` colors,
+/// named like `Blues_5`.
+///
+/// Without `breaks`, the classes are bounded by `0, 1, ..., nb_class`. Return an error
+/// if the palette doesn't define this number of colors or if `breaks` doesn't contain
+/// `nb_class + 1` strictly increasing finite values.
+pub fn write_palette(
+    palette: Palette,
+    nb_class: u32,
+    breaks: Option<&[f64]>,
+) -> Result<String, Error> {
+    let breaks = match breaks {
+        Some(breaks) => breaks.to_vec(),
+        None => (0..=nb_class).map(f64::from).collect(),
+    };
+    let classifier = Classifier::new(palette, nb_class, breaks)?;
+    Ok(write(&format!("{}_{}", palette, nb_class), &classifier))
+}
+
+/// Color palette tables of every color ramp of every palette, with the classes
+/// bounded by `0, 1, ..., nb_class`, as `(file name, content)` pairs.
+/// The files are named like `Blues_5.cpt`.
+pub fn library() -> Vec<(String, String)> {
+    library_ramps()
+        .map(|NamedRamp { name, ramp }| {
+            let breaks = (0..=ramp.len()).map(|i| i as f64).collect();
+            let classifier = Classifier::from_colors(ramp.into_colors(), breaks).unwrap();
+            (format!("{}.cpt", name), write(&name, &classifier))
+        })
+        .collect()
+}
+
+fn parse_bound<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<f64, Error> {
+    tokens
+        .next()
+        .and_then(|t| t.parse::<f64>().ok())
+        .ok_or(Error::InvalidPaletteFile("invalid slice bound"))
+}
+
+/// Parse a color given as `r/g/b` (the slashes having been replaced by spaces)
+/// or as `#rrggbb`.
+fn parse_color<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<RGB<u8>, Error> {
+    let invalid = || Error::InvalidPaletteFile("invalid color");
+    let first = tokens.next().ok_or_else(invalid)?;
+    if first.starts_with('#') {
+        return parse_hex(first).map_err(|_| invalid());
+    }
+    let component =
+        |token: Option<&str>| token.and_then(|t| t.parse::<u8>().ok()).ok_or_else(invalid);
+    Ok(RGB {
+        r: component(Some(first))?,
+        g: component(tokens.next())?,
+        b: component(tokens.next())?,
+    })
+}
+
+/// Read a color palette table with one slice per class. The color of each class is
+/// the color at the start of its slice. The background, foreground and no data
+/// colors are ignored.
+pub fn read(content: &str) -> Result<Classifier, Error> {
+    let mut colors = Vec::new();
+    let mut breaks: Vec<f64> = Vec::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(['B', 'F', 'N']) {
+            continue;
+        }
+        let line = line.replace('/', " ");
+        let mut tokens = line.split_whitespace();
+        let lower = parse_bound(&mut tokens)?;
+        let color = parse_color(&mut tokens)?;
+        let upper = parse_bound(&mut tokens)?;
+        parse_color(&mut tokens)?;
+        match breaks.last() {
+            None => breaks.push(lower),
+            Some(&last) if last != lower => {
+                return Err(Error::InvalidPaletteFile("the slices must be contiguous"))
+            }
+            Some(_) => (),
+        }
+        breaks.push(upper);
+        colors.push(color);
+    }
+    if colors.is_empty() {
+        return Err(Error::InvalidPaletteFile("no color slice"));
+    }
+    Classifier::from_colors(colors, breaks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let content = write_palette(Palette::Greens, 3, None).unwrap();
+        assert_eq!(
+            content,
+            "# Greens_3\n# COLOR_MODEL = RGB\n\
+             0\t229/245/224\t1\t229/245/224\n\
+             1\t161/217/155\t2\t161/217/155\n\
+             2\t49/163/84\t3\t49/163/84\n\
+             B\t229/245/224\nF\t49/163/84\n"
+        );
+        assert_eq!(library().len(), 265);
+    }
+
+    #[test]
+    fn test_read() {
+        let content = "# comment\n0 255 0 0 10 255 0 0 ; label\n10 #0000ff 25.5 #000000 U\nN 128\n";
+        let classifier = read(content).unwrap();
+        assert_eq!(classifier.breaks(), &[0., 10., 25.5]);
+        assert_eq!(
+            classifier.colors(),
+            &[RGB::new(255, 0, 0), RGB::new(0, 0, 255)]
+        );
+        assert!(read("0 255/0/0 1 255/0/0\n2 0/0/0 3 0/0/0\n").is_err());
+        assert!(read("0 255/0 1 255/0/0\n").is_err());
+        assert!(read("# empty\n").is_err());
+    }
+}
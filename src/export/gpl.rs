@@ -0,0 +1,141 @@
+//! GIMP palettes (`.gpl`), also used by Inkscape, Krita and Scribus.
+//!
+//! ```rust
+//! use colorbrewer::export::gpl;
+//! use colorbrewer::Palette;
+//!
+//! let content = gpl::write_palette(Palette::Purples, 3).unwrap();
+//! assert!(content.starts_with("GIMP Palette\nName: Purples_3\n"));
+//! let read = gpl::read(&content).unwrap();
+//! assert_eq!(read.name, "Purples_3");
+//! assert_eq!(read.ramp, Palette::Purples.ramp(3).unwrap());
+//! ```
+
+use crate::export::{library_ramps, NamedRamp};
+use crate::format::to_hex;
+use crate::{Error, Palette, Ramp};
+use rgb::RGB;
+use std::fmt::Write;
+
+/// Remove the line breaks, which would end a name early.
+fn single_line(name: &str) -> String {
+    name.replace(['\r', '\n'], " ")
+}
+
+fn write_header(out: &mut String, name: &str, columns: usize) {
+    out.push_str("GIMP Palette\n");
+    writeln!(out, "Name: {}", single_line(name)).unwrap();
+    writeln!(out, "Columns: {}", columns).unwrap();
+    out.push_str("#\n");
+}
+
+fn write_color(out: &mut String, color: RGB<u8>, name: &str) {
+    writeln!(
+        out,
+        "{:3} {:3} {:3}\t{}",
+        color.r,
+        color.g,
+        color.b,
+        single_line(name)
+    )
+    .unwrap();
+}
+
+/// GIMP palette named `name` containing `colors`, each color being named after
+/// its hexadecimal code.
+pub fn write(name: &str, colors: &[RGB<u8>]) -> String {
+    let mut out = String::new();
+    write_header(&mut out, name, colors.len());
+    for &color in colors {
+        write_color(&mut out, color, &to_hex(color));
+    }
+    out
+}
+
+/// GIMP palette containing the ramp of `palette` with `nb_value` colors,
+/// named like `Blues_5`.
+///
+/// Return an error if the palette doesn't define this number of colors.
+pub fn write_palette(palette: Palette, nb_value: u32) -> Result<String, Error> {
+    let colors = palette.try_colors(nb_value)?;
+    Ok(write(&format!("{}_{}", palette, nb_value), colors))
+}
+
+/// GIMP palette named `ColorBrewer` containing every color ramp of every palette,
+/// each color being named like `Blues_5 1`.
+pub fn library() -> String {
+    let mut out = String::new();
+    write_header(&mut out, "ColorBrewer", 0);
+    for NamedRamp { name, ramp } in library_ramps() {
+        writeln!(out, "# {}", name).unwrap();
+        for (i, &color) in ramp.iter().enumerate() {
+            write_color(&mut out, color, &format!("{} {}", name, i + 1));
+        }
+    }
+    out
+}
+
+/// Read the name and the colors of a GIMP palette.
+pub fn read(content: &str) -> Result<NamedRamp, Error> {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim) != Some("GIMP Palette") {
+        return Err(Error::InvalidPaletteFile("missing \"GIMP Palette\" header"));
+    }
+    let mut name = String::new();
+    let mut colors = Vec::new();
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("Columns:") {
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("Name:") {
+            name = value.trim().to_owned();
+            continue;
+        }
+        let mut components = line.split_whitespace().map(str::parse::<u8>);
+        match (components.next(), components.next(), components.next()) {
+            (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => colors.push(RGB { r, g, b }),
+            _ => return Err(Error::InvalidPaletteFile("invalid color line")),
+        }
+    }
+    Ok(NamedRamp {
+        name,
+        ramp: Ramp::new(colors),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let content = write("Two\ncolors", &[RGB::new(0, 10, 255), RGB::new(1, 2, 3)]);
+        assert_eq!(
+            content,
+            "GIMP Palette\nName: Two colors\nColumns: 2\n#\n  0  10 255\t#000aff\n  1   2   3\t#010203\n"
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let content = "GIMP Palette\r\nName: Test\r\n# comment\r\n\r\n255 0 0 Red\r\n0 128 0\r\n";
+        let read = read(content).unwrap();
+        assert_eq!(read.name, "Test");
+        assert_eq!(
+            read.ramp.colors(),
+            &[RGB::new(255, 0, 0), RGB::new(0, 128, 0)]
+        );
+        assert!(super::read("Not a palette").is_err());
+        assert!(super::read("GIMP Palette\n255 0\n").is_err());
+    }
+
+    #[test]
+    fn test_library() {
+        let library = read(&library()).unwrap();
+        let count: u32 = Palette::iter()
+            .map(|p| (p.min_classes()..=p.max_classes()).sum::<u32>())
+            .sum();
+        assert_eq!(library.ramp.len(), count as usize);
+    }
+}
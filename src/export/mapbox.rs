@@ -0,0 +1,149 @@
+//! Mapbox GL (and MapLibre GL) style expressions, as JSON strings to be used for
+//! example as the `fill-color` paint property of a layer.
+//!
+//! ```rust
+//! use colorbrewer::export::mapbox;
+//! use colorbrewer::Palette;
+//!
+//! let expression = mapbox::step_expression(Palette::Blues, 3, "density", &[0., 10., 50., 100.])
+//!     .unwrap();
+//! assert_eq!(
+//!     expression,
+//!     r##"["step",["get","density"],"#deebf7",10,"#9ecae1",50,"#3182bd"]"##
+//! );
+//! ```
+
+use crate::format::to_hex;
+use crate::{Classifier, Error, Palette};
+use std::fmt::Write;
+
+/// Format `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// `step` expression giving each feature the color of the class of `attribute`,
+/// using the ramp of `palette` with `nb_class` colors and the given `breaks`.
+///
+/// The values below the second break get the first color. Unlike [`Classifier`],
+/// a `step` expression includes the lower bound of each class instead of its upper one.
+///
+/// Return an error if the palette doesn't define this number of colors or if `breaks`
+/// doesn't contain `nb_class + 1` strictly increasing finite values.
+pub fn step_expression(
+    palette: Palette,
+    nb_class: u32,
+    attribute: &str,
+    breaks: &[f64],
+) -> Result<String, Error> {
+    let classifier = Classifier::new(palette, nb_class, breaks.to_vec())?;
+    Ok(classifier_step_expression(&classifier, attribute))
+}
+
+/// `step` expression giving each feature the color of the class of `attribute`,
+/// using the colors and breaks of `classifier` (see [`step_expression`]).
+pub fn classifier_step_expression(classifier: &Classifier, attribute: &str) -> String {
+    let (colors, breaks) = (classifier.colors(), classifier.breaks());
+    let mut out = format!("[\"step\",[\"get\",{}],", json_string(attribute));
+    write!(out, "\"{}\"", to_hex(colors[0])).unwrap();
+    for (&color, &stop) in colors[1..].iter().zip(&breaks[1..]) {
+        write!(out, ",{},\"{}\"", stop, to_hex(color)).unwrap();
+    }
+    out.push(']');
+    out
+}
+
+/// `interpolate` expression giving each feature a color linearly interpolated from
+/// `attribute`, using the ramp of `palette` with `nb_class` colors, each color being
+/// placed at the middle of its class.
+///
+/// Return an error if the palette doesn't define this number of colors or if `breaks`
+/// doesn't contain `nb_class + 1` strictly increasing finite values.
+pub fn interpolate_expression(
+    palette: Palette,
+    nb_class: u32,
+    attribute: &str,
+    breaks: &[f64],
+) -> Result<String, Error> {
+    let classifier = Classifier::new(palette, nb_class, breaks.to_vec())?;
+    Ok(classifier_interpolate_expression(&classifier, attribute))
+}
+
+/// `interpolate` expression giving each feature a color linearly interpolated from
+/// `attribute`, using the colors and breaks of `classifier`
+/// (see [`interpolate_expression`]).
+pub fn classifier_interpolate_expression(classifier: &Classifier, attribute: &str) -> String {
+    let mut out = format!(
+        "[\"interpolate\",[\"linear\"],[\"get\",{}]",
+        json_string(attribute)
+    );
+    let breaks = classifier.breaks();
+    for (&color, bounds) in classifier.colors().iter().zip(breaks.windows(2)) {
+        let stop = (bounds[0] + bounds[1]) / 2.;
+        write!(out, ",{},\"{}\"", stop, to_hex(color)).unwrap();
+    }
+    out.push(']');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_step_expression() {
+        let expression = step_expression(Palette::Reds, 3, "a\"b", &[0., 1., 2.5, 3.]).unwrap();
+        let parsed: Value = serde_json::from_str(&expression).unwrap();
+        assert_eq!(
+            parsed,
+            json!([
+                "step",
+                ["get", "a\"b"],
+                "#fee0d2",
+                1,
+                "#fc9272",
+                2.5,
+                "#de2d26"
+            ])
+        );
+        assert!(step_expression(Palette::Reds, 3, "a", &[0., 2., 1., 3.]).is_err());
+        // Mapbox GL rejects repeated stops.
+        assert!(step_expression(Palette::Reds, 3, "a", &[0., 0., 1., 3.]).is_err());
+        assert!(interpolate_expression(Palette::Reds, 3, "a", &[0., 1., 1., 3.]).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_expression() {
+        let expression = interpolate_expression(Palette::Reds, 3, "a", &[0., 2., 4., 6.]).unwrap();
+        let parsed: Value = serde_json::from_str(&expression).unwrap();
+        assert_eq!(
+            parsed,
+            json!([
+                "interpolate",
+                ["linear"],
+                ["get", "a"],
+                1,
+                "#fee0d2",
+                3,
+                "#fc9272",
+                5,
+                "#de2d26"
+            ])
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! QGIS styles: graduated symbol renderers (`.qml` layer styles) and color ramp
+//! libraries (style `.xml` files, imported with the Style Manager).
+//!
+//! ```rust
+//! use colorbrewer::export::qgis;
+//! use colorbrewer::Palette;
+//!
+//! let qml = qgis::graduated_style(Palette::YlOrRd, 4, "density", Some(&[0., 10., 50., 100., 500.]))
+//!     .unwrap();
+//! assert!(qml.contains(r#"<range lower="0" upper="10" symbol="0" label="0 - 10" render="true"/>"#));
+//!
+//! let library = qgis::color_ramp_library();
+//! assert!(library.contains(r#"<colorramp type="gradient" name="YlOrRd_4""#));
+//! ```
+
+use crate::export::escape_xml;
+use crate::{Classifier, Error, Palette};
+use rgb::RGB;
+use std::fmt::Write;
+
+/// Format a color as a QGIS `r,g,b,a` string.
+fn color(color: RGB<u8>) -> String {
+    format!("{},{},{},255", color.r, color.g, color.b)
+}
+
+/// Write the `<colorramp>` element of a discrete gradient going through `colors`.
+fn write_color_ramp(out: &mut String, name: &str, tags: &str, colors: &[RGB<u8>], indent: &str) {
+    let n = colors.len();
+    let stops: Vec<String> = (1..n)
+        .map(|i| format!("{};{}", i as f64 / n as f64, color(colors[i])))
+        .collect();
+    let tags = if tags.is_empty() {
+        String::new()
+    } else {
+        format!(" tags=\"{}\"", escape_xml(tags))
+    };
+    writeln!(
+        out,
+        "{}<colorramp type=\"gradient\" name=\"{}\"{}>",
+        indent,
+        escape_xml(name),
+        tags
+    )
+    .unwrap();
+    let props = [
+        ("color1", color(colors[0])),
+        ("color2", color(colors[n - 1])),
+        ("discrete", "1".to_owned()),
+        ("rampType", "gradient".to_owned()),
+        ("stops", stops.join(":")),
+    ];
+    for (key, value) in &props {
+        writeln!(out, "{}  <prop k=\"{}\" v=\"{}\"/>", indent, key, value).unwrap();
+    }
+    writeln!(out, "{}</colorramp>", indent).unwrap();
+}
+
+/// QGIS layer style (`.qml`) rendering a polygon layer with a graduated symbol
+/// renderer on `attribute`, using the ramp of `palette` with `nb_class` colors.
+///
+/// Without `breaks`, the classes are bounded by `0, 1, ..., nb_class`, to be
+/// reclassified in QGIS. Return an error if the palette doesn't define this number
+/// of colors or if `breaks` doesn't contain `nb_class + 1` strictly increasing finite
+/// values.
+pub fn graduated_style(
+    palette: Palette,
+    nb_class: u32,
+    attribute: &str,
+    breaks: Option<&[f64]>,
+) -> Result<String, Error> {
+    let breaks = match breaks {
+        Some(breaks) => breaks.to_vec(),
+        None => (0..=nb_class).map(f64::from).collect(),
+    };
+    let classifier = Classifier::new(palette, nb_class, breaks)?;
+    Ok(classifier_style(&classifier, attribute))
+}
+
+/// QGIS layer style (`.qml`) rendering a polygon layer with a graduated symbol
+/// renderer on `attribute`, using the colors and breaks of `classifier`.
+pub fn classifier_style(classifier: &Classifier, attribute: &str) -> String {
+    let (colors, breaks) = (classifier.colors(), classifier.breaks());
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE qgis PUBLIC 'http://mrcc.com/qgis.dtd' 'SYSTEM'>\n");
+    out.push_str("<qgis version=\"3.22.0\" styleCategories=\"Symbology\">\n");
+    writeln!(
+        out,
+        "  <renderer-v2 type=\"graduatedSymbol\" attr=\"{}\" graduatedMethod=\"GraduatedColor\" \
+         symbollevels=\"0\" enableorderby=\"0\" forceraster=\"0\">",
+        escape_xml(attribute)
+    )
+    .unwrap();
+    out.push_str("    <ranges>\n");
+    for (i, bounds) in breaks.windows(2).enumerate() {
+        writeln!(
+            out,
+            "      <range lower=\"{lower}\" upper=\"{upper}\" symbol=\"{i}\" \
+             label=\"{lower} - {upper}\" render=\"true\"/>",
+            lower = bounds[0],
+            upper = bounds[1],
+            i = i
+        )
+        .unwrap();
+    }
+    out.push_str("    </ranges>\n");
+    out.push_str("    <symbols>\n");
+    for (i, &fill) in colors.iter().enumerate() {
+        writeln!(
+            out,
+            "      <symbol type=\"fill\" name=\"{}\" alpha=\"1\" clip_to_extent=\"1\" force_rhr=\"0\">",
+            i
+        )
+        .unwrap();
+        out.push_str(
+            "        <layer class=\"SimpleFill\" enabled=\"1\" locked=\"0\" pass=\"0\">\n",
+        );
+        let fill = color(fill);
+        let props = [
+            ("color", fill.as_str()),
+            ("outline_color", "35,35,35,255"),
+            ("outline_style", "solid"),
+            ("outline_width", "0.26"),
+            ("outline_width_unit", "MM"),
+            ("style", "solid"),
+        ];
+        for (key, value) in &props {
+            writeln!(out, "          <prop k=\"{}\" v=\"{}\"/>", key, value).unwrap();
+        }
+        out.push_str("        </layer>\n");
+        out.push_str("      </symbol>\n");
+    }
+    out.push_str("    </symbols>\n");
+    write_color_ramp(&mut out, "[source]", "", colors, "    ");
+    out.push_str("    <classificationMethod id=\"Custom\"/>\n");
+    out.push_str("  </renderer-v2>\n");
+    out.push_str("</qgis>\n");
+    out
+}
+
+/// QGIS style library (`.xml`) containing a discrete color ramp for each palette
+/// and each of its numbers of colors, named like `Blues_5` and tagged with
+/// `colorbrewer` and the scheme type of the palette.
+pub fn color_ramp_library() -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE qgis_style>\n");
+    out.push_str("<qgis_style version=\"2\">\n");
+    out.push_str("  <symbols/>\n");
+    out.push_str("  <colorramps>\n");
+    for palette in Palette::iter() {
+        let tags = format!("colorbrewer,{:?}", palette.scheme_type()).to_lowercase();
+        for nb_value in palette.min_classes()..=palette.max_classes() {
+            let name = format!("{}_{}", palette, nb_value);
+            let colors = palette.colors(nb_value).unwrap();
+            write_color_ramp(&mut out, &name, &tags, colors, "    ");
+        }
+    }
+    out.push_str("  </colorramps>\n");
+    out.push_str("</qgis_style>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graduated_style() {
+        let qml = graduated_style(Palette::Blues, 3, "a<b", None).unwrap();
+        assert!(qml.contains("attr=\"a&lt;b\""));
+        assert!(qml.contains("<range lower=\"2\" upper=\"3\" symbol=\"2\""));
+        assert!(qml.contains("<prop k=\"color\" v=\"222,235,247,255\"/>"));
+        assert!(qml.contains("<prop k=\"color\" v=\"49,130,189,255\"/>"));
+        assert!(qml.contains(
+            "<prop k=\"stops\" v=\"0.3333333333333333;158,202,225,255:0.6666666666666666;49,130,189,255\"/>"
+        ));
+        assert_eq!(qml.matches("<symbol ").count(), 3);
+        assert!(graduated_style(Palette::Blues, 3, "a", Some(&[0., 1.])).is_err());
+        assert!(graduated_style(Palette::Blues, 10, "a", None).is_err());
+    }
+
+    #[test]
+    fn test_color_ramp_library() {
+        let library = color_ramp_library();
+        let count: u32 = Palette::iter()
+            .map(|p| p.max_classes() - p.min_classes() + 1)
+            .sum();
+        assert_eq!(library.matches("<colorramp ").count(), count as usize);
+        assert!(library.contains(
+            "<colorramp type=\"gradient\" name=\"Set3_12\" tags=\"colorbrewer,qualitative\">"
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+//! OGC Styled Layer Descriptor (SLD 1.0) `FeatureTypeStyle`, with one rule per class,
+//! to be embedded in the `UserStyle` of an SLD document (for example for GeoServer).
+//!
+//! ```rust
+//! use colorbrewer::export::sld;
+//! use colorbrewer::Palette;
+//!
+//! let style = sld::feature_type_style(Palette::Greens, 3, "density", &[0., 10., 50., 100.]).unwrap();
+//! assert!(style.contains("<CssParameter name=\"fill\">#31a354</CssParameter>"));
+//! ```
+
+use crate::export::escape_xml;
+use crate::format::to_hex;
+use crate::{Classifier, Error, Palette};
+use std::fmt::Write;
+
+/// SLD `FeatureTypeStyle` filling the polygons according to the class of `attribute`,
+/// using the ramp of `palette` with `nb_class` colors and the given `breaks`.
+///
+/// Return an error if the palette doesn't define this number of colors or if `breaks`
+/// doesn't contain `nb_class + 1` strictly increasing finite values.
+pub fn feature_type_style(
+    palette: Palette,
+    nb_class: u32,
+    attribute: &str,
+    breaks: &[f64],
+) -> Result<String, Error> {
+    let classifier = Classifier::new(palette, nb_class, breaks.to_vec())?;
+    Ok(classifier_style(&classifier, attribute))
+}
+
+/// SLD `FeatureTypeStyle` filling the polygons according to the class of `attribute`,
+/// using the colors and breaks of `classifier`.
+///
+/// As with [`Classifier::class_of`], each class includes its upper bound and the first
+/// class also includes the first break.
+pub fn classifier_style(classifier: &Classifier, attribute: &str) -> String {
+    let attribute = escape_xml(attribute);
+    let mut out = String::new();
+    out.push_str(
+        "<FeatureTypeStyle xmlns=\"http://www.opengis.net/sld\" \
+         xmlns:ogc=\"http://www.opengis.net/ogc\">\n",
+    );
+    let breaks = classifier.breaks();
+    for (i, &fill) in classifier.colors().iter().enumerate() {
+        let (lower, upper) = (breaks[i], breaks[i + 1]);
+        let lower_operator = if i == 0 {
+            "PropertyIsGreaterThanOrEqualTo"
+        } else {
+            "PropertyIsGreaterThan"
+        };
+        out.push_str("  <Rule>\n");
+        writeln!(out, "    <Name>class {}</Name>", i + 1).unwrap();
+        writeln!(out, "    <Title>{} - {}</Title>", lower, upper).unwrap();
+        out.push_str("    <ogc:Filter>\n");
+        out.push_str("      <ogc:And>\n");
+        for (operator, value) in &[
+            (lower_operator, lower),
+            ("PropertyIsLessThanOrEqualTo", upper),
+        ] {
+            writeln!(
+                out,
+                "        <ogc:{op}><ogc:PropertyName>{}</ogc:PropertyName>\
+                 <ogc:Literal>{}</ogc:Literal></ogc:{op}>",
+                attribute,
+                value,
+                op = operator
+            )
+            .unwrap();
+        }
+        out.push_str("      </ogc:And>\n");
+        out.push_str("    </ogc:Filter>\n");
+        out.push_str("    <PolygonSymbolizer>\n");
+        out.push_str("      <Fill>\n");
+        writeln!(
+            out,
+            "        <CssParameter name=\"fill\">{}</CssParameter>",
+            to_hex(fill)
+        )
+        .unwrap();
+        out.push_str("      </Fill>\n");
+        out.push_str("      <Stroke>\n");
+        out.push_str("        <CssParameter name=\"stroke\">#232323</CssParameter>\n");
+        out.push_str("        <CssParameter name=\"stroke-width\">0.5</CssParameter>\n");
+        out.push_str("      </Stroke>\n");
+        out.push_str("    </PolygonSymbolizer>\n");
+        out.push_str("  </Rule>\n");
+    }
+    out.push_str("</FeatureTypeStyle>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_type_style() {
+        let style = feature_type_style(Palette::Reds, 3, "value", &[0., 1., 2., 3.]).unwrap();
+        assert_eq!(style.matches("<Rule>").count(), 3);
+        assert!(style.contains(
+            "<ogc:PropertyIsGreaterThanOrEqualTo><ogc:PropertyName>value</ogc:PropertyName>\
+             <ogc:Literal>0</ogc:Literal></ogc:PropertyIsGreaterThanOrEqualTo>"
+        ));
+        assert_eq!(style.matches("<ogc:PropertyIsGreaterThan>").count(), 2);
+        assert!(style.contains("<CssParameter name=\"fill\">#de2d26</CssParameter>"));
+        assert!(feature_type_style(Palette::Reds, 3, "value", &[0., 1., 2.]).is_err());
+    }
+}
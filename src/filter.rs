@@ -0,0 +1,156 @@
+//! Query builder to select palettes according to their metadata.
+
+use crate::{Palette, Properties, SchemeType, Suitability};
+
+/// Builder selecting the palettes matching some criteria,
+/// created with [`Palette::filter`].
+///
+/// Each criterion narrows the selection. When a number of colors is given with
+/// [`PaletteFilter::classes`], the properties are checked on the ramp with this number
+/// of colors, otherwise a palette matches if at least one of its ramps has all the
+/// requested properties. Only the properties rated [`Suitability::Yes`] by ColorBrewer
+/// are considered as satisfied.
+///
+/// ```rust
+/// use colorbrewer::{Palette, SchemeType};
+///
+/// let palettes = Palette::filter()
+///     .kind(SchemeType::Diverging)
+///     .classes(7)
+///     .colorblind_safe()
+///     .palettes();
+/// assert!(palettes.contains(&Palette::RdBu));
+/// assert!(!palettes.contains(&Palette::Spectral));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaletteFilter {
+    kind: Option<SchemeType>,
+    classes: Option<u32>,
+    colorblind_safe: bool,
+    print_friendly: bool,
+    photocopy_safe: bool,
+    lcd_friendly: bool,
+}
+
+impl PaletteFilter {
+    /// Only keep the palettes of this scheme type.
+    pub fn kind(mut self, kind: SchemeType) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Only keep the palettes defining a color ramp with `nb_value` colors.
+    pub fn classes(mut self, nb_value: u32) -> Self {
+        self.classes = Some(nb_value);
+        self
+    }
+
+    /// Only keep the palettes rated as colorblind safe.
+    pub fn colorblind_safe(mut self) -> Self {
+        self.colorblind_safe = true;
+        self
+    }
+
+    /// Only keep the palettes rated as print friendly.
+    pub fn print_friendly(mut self) -> Self {
+        self.print_friendly = true;
+        self
+    }
+
+    /// Only keep the palettes rated as photocopy safe.
+    pub fn photocopy_safe(mut self) -> Self {
+        self.photocopy_safe = true;
+        self
+    }
+
+    /// Only keep the palettes rated as LCD friendly.
+    pub fn lcd_friendly(mut self) -> Self {
+        self.lcd_friendly = true;
+        self
+    }
+
+    /// Whether `palette` matches all the criteria of this filter.
+    pub fn matches(&self, palette: Palette) -> bool {
+        if let Some(kind) = self.kind {
+            if palette.scheme_type() != kind {
+                return false;
+            }
+        }
+        match self.classes {
+            Some(nb_value) => palette
+                .properties(nb_value)
+                .is_some_and(|properties| self.accepts(&properties)),
+            None => (palette.min_classes()..=palette.max_classes())
+                .filter_map(|nb_value| palette.properties(nb_value))
+                .any(|properties| self.accepts(&properties)),
+        }
+    }
+
+    /// Iterate over the palettes matching this filter.
+    pub fn iter(&self) -> impl Iterator<Item = Palette> + '_ {
+        Palette::iter().filter(move |palette| self.matches(*palette))
+    }
+
+    /// Collect the palettes matching this filter.
+    pub fn palettes(&self) -> Vec<Palette> {
+        self.iter().collect()
+    }
+
+    fn accepts(&self, properties: &Properties) -> bool {
+        (!self.colorblind_safe || properties.colorblind_safe == Suitability::Yes)
+            && (!self.print_friendly || properties.print_friendly == Suitability::Yes)
+            && (!self.photocopy_safe || properties.photocopy_safe == Suitability::Yes)
+            && (!self.lcd_friendly || properties.lcd_friendly == Suitability::Yes)
+    }
+}
+
+impl Palette {
+    /// Create a [`PaletteFilter`] selecting, until narrowed, every palette.
+    pub fn filter() -> PaletteFilter {
+        PaletteFilter::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Palette, SchemeType};
+
+    #[test]
+    fn test_filter_kind() {
+        let qualitative = Palette::filter().kind(SchemeType::Qualitative).palettes();
+        assert_eq!(
+            qualitative,
+            vec![
+                Palette::Accent,
+                Palette::Dark2,
+                Palette::Paired,
+                Palette::Pastel1,
+                Palette::Pastel2,
+                Palette::Set1,
+                Palette::Set2,
+                Palette::Set3,
+            ]
+        );
+        assert_eq!(Palette::filter().palettes().len(), 35);
+    }
+
+    #[test]
+    fn test_filter_classes_and_properties() {
+        let palettes = Palette::filter().classes(12).palettes();
+        assert_eq!(palettes, vec![Palette::Paired, Palette::Set3]);
+
+        let palettes = Palette::filter()
+            .kind(SchemeType::Qualitative)
+            .classes(3)
+            .colorblind_safe()
+            .palettes();
+        assert_eq!(
+            palettes,
+            vec![Palette::Dark2, Palette::Paired, Palette::Set2]
+        );
+
+        let palettes = Palette::filter().photocopy_safe().lcd_friendly().palettes();
+        assert!(palettes.contains(&Palette::BrBG));
+        assert!(!palettes.contains(&Palette::Set1));
+    }
+}
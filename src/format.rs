@@ -0,0 +1,121 @@
+//! Conversions of colors from and to their usual textual and numeric representations.
+//!
+//! ```rust
+//! use colorbrewer::format::{parse_hex, to_css, to_hex, to_u32};
+//!
+//! let color = parse_hex("#3182bd").unwrap();
+//! assert_eq!(color, rgb::RGB { r: 49, g: 130, b: 189 });
+//! assert_eq!(to_hex(color), "#3182bd");
+//! assert_eq!(to_css(color), "rgb(49, 130, 189)");
+//! assert_eq!(to_u32(color), 0x3182bd);
+//! ```
+
+use crate::Error;
+use rgb::RGB;
+
+/// Format a color as a `#rrggbb` hexadecimal string.
+pub fn to_hex(color: RGB<u8>) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// Format a color as a CSS `rgb(r, g, b)` functional notation.
+pub fn to_css(color: RGB<u8>) -> String {
+    format!("rgb({}, {}, {})", color.r, color.g, color.b)
+}
+
+/// Pack a color as a `0xRRGGBB` integer.
+pub fn to_u32(color: RGB<u8>) -> u32 {
+    u32::from(color.r) << 16 | u32::from(color.g) << 8 | u32::from(color.b)
+}
+
+/// Unpack a color from a `0xRRGGBB` integer (the highest byte is ignored).
+pub fn from_u32(value: u32) -> RGB<u8> {
+    RGB {
+        r: (value >> 16) as u8,
+        g: (value >> 8) as u8,
+        b: value as u8,
+    }
+}
+
+/// Normalize the components of a color to `[0, 1]`.
+pub fn to_f32(color: RGB<u8>) -> [f32; 3] {
+    [
+        f32::from(color.r) / 255.,
+        f32::from(color.g) / 255.,
+        f32::from(color.b) / 255.,
+    ]
+}
+
+/// Parse a `#rrggbb` or `#rgb` hexadecimal color, the `#` being optional.
+/// Return an [`Error::InvalidColor`] if `s` isn't a valid hexadecimal color.
+pub fn parse_hex(s: &str) -> Result<RGB<u8>, Error> {
+    let digits = s.trim();
+    let digits = digits.strip_prefix('#').unwrap_or(digits);
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidColor(s.to_owned()));
+    }
+    let component = |d: &str| u8::from_str_radix(d, 16).unwrap();
+    match digits.len() {
+        6 => Ok(RGB {
+            r: component(&digits[0..2]),
+            g: component(&digits[2..4]),
+            b: component(&digits[4..6]),
+        }),
+        3 => Ok(RGB {
+            r: component(&digits[0..1]) * 17,
+            g: component(&digits[1..2]) * 17,
+            b: component(&digits[2..3]) * 17,
+        }),
+        _ => Err(Error::InvalidColor(s.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Palette, Ramp};
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#fdae6b"), Ok(RGB::new(253, 174, 107)));
+        assert_eq!(parse_hex("FDAE6B"), Ok(RGB::new(253, 174, 107)));
+        assert_eq!(parse_hex("#f80"), Ok(RGB::new(255, 136, 0)));
+        assert_eq!(
+            parse_hex("#fdae6"),
+            Err(Error::InvalidColor("#fdae6".to_owned()))
+        );
+        assert!(parse_hex("#fdae6g").is_err());
+        assert!(parse_hex("").is_err());
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for palette in Palette::iter() {
+            for &color in palette.colors(palette.max_classes()).unwrap() {
+                assert_eq!(parse_hex(&to_hex(color)), Ok(color));
+                assert_eq!(from_u32(to_u32(color)), color);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ramp_conversions() {
+        let ramp = Palette::Oranges.ramp(3).unwrap();
+        assert_eq!(ramp.to_hex(), vec!["#fee6ce", "#fdae6b", "#e6550d"]);
+        assert_eq!(
+            ramp.to_css(),
+            vec![
+                "rgb(254, 230, 206)",
+                "rgb(253, 174, 107)",
+                "rgb(230, 85, 13)"
+            ]
+        );
+        assert_eq!(ramp.to_u32(), vec![0xfee6ce, 0xfdae6b, 0xe6550d]);
+        assert_eq!(ramp.to_f32()[2], [230. / 255., 85. / 255., 13. / 255.]);
+        assert_eq!(Ramp::from_hex(&["#fee6ce", "fdae6b", "#E6550D"]), Ok(ramp));
+        assert_eq!(
+            Ramp::from_hex(&["#fee6ce", "orange"]),
+            Err(Error::InvalidColor("orange".to_owned()))
+        );
+    }
+}
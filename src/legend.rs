@@ -0,0 +1,498 @@
+//! SVG rendering of map legends and color swatches.
+//!
+//! ```rust
+//! use colorbrewer::legend::{BarStyle, Legend, Orientation};
+//! use colorbrewer::Palette;
+//!
+//! let svg = Legend::new(Palette::YlOrRd, 4, &[0., 10., 50., 100., 500.])
+//!     .unwrap()
+//!     .title("Population density")
+//!     .unit(" /km²")
+//!     .orientation(Orientation::Vertical)
+//!     .style(BarStyle::Stepped)
+//!     .no_data("No data")
+//!     .to_svg();
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains(">500 /km²</text>"));
+//! ```
+
+use crate::classifier::DEFAULT_NO_DATA;
+use crate::export::escape_xml;
+use crate::format::to_hex;
+use crate::{Classifier, Error, Palette};
+use rgb::RGB;
+use std::fmt::Write;
+
+const FONT_SIZE: f64 = 12.;
+/// Approximate width of a character, to size the drawing without measuring the text.
+const CHAR_WIDTH: f64 = 7.;
+const MARGIN: f64 = 8.;
+const TITLE_HEIGHT: f64 = 20.;
+const TICK_LENGTH: f64 = 4.;
+/// Space between the bar and the no data swatch, and between a tick and its label.
+const GAP: f64 = 12.;
+
+/// Direction of the bar of a legend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Orientation {
+    /// From left (first class) to right (last class), with the labels below the bar.
+    #[default]
+    Horizontal,
+    /// From bottom (first class) to top (last class), with the labels right of the bar.
+    Vertical,
+}
+
+/// Drawing of the bar of a legend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BarStyle {
+    /// One box of uniform color per class.
+    #[default]
+    Stepped,
+    /// A gradient going through the colors of the classes.
+    Continuous,
+}
+
+/// Legend of a classified map: a bar with the colors of the classes and a label for
+/// each break, with an optional title and no data swatch.
+///
+/// The classes get the same length on the bar, whatever the width of their interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legend {
+    colors: Vec<RGB<u8>>,
+    breaks: Vec<f64>,
+    labels: Option<Vec<String>>,
+    title: Option<String>,
+    no_data: Option<String>,
+    no_data_color: RGB<u8>,
+    orientation: Orientation,
+    style: BarStyle,
+    decimals: Option<usize>,
+    unit: String,
+    length: f64,
+    thickness: f64,
+}
+
+impl Legend {
+    /// Create a legend of the ramp of `palette` with `nb_class` colors, labelled
+    /// with the `breaks`.
+    ///
+    /// Return an error if the palette doesn't define this number of colors or if
+    /// `breaks` doesn't contain `nb_class + 1` strictly increasing finite values.
+    pub fn new(palette: Palette, nb_class: u32, breaks: &[f64]) -> Result<Self, Error> {
+        Classifier::new(palette, nb_class, breaks.to_vec()).map(|c| Legend::from_classifier(&c))
+    }
+
+    /// Create a legend of the colors and breaks of `classifier`.
+    pub fn from_classifier(classifier: &Classifier) -> Self {
+        Legend {
+            colors: classifier.colors().to_vec(),
+            breaks: classifier.breaks().to_vec(),
+            labels: None,
+            title: None,
+            no_data: None,
+            no_data_color: DEFAULT_NO_DATA,
+            orientation: Orientation::default(),
+            style: BarStyle::default(),
+            decimals: None,
+            unit: String::new(),
+            length: 240.,
+            thickness: 16.,
+        }
+    }
+
+    /// Set the direction of the bar ([`Orientation::Horizontal`] by default).
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set how the bar is drawn ([`BarStyle::Stepped`] by default).
+    pub fn style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the title drawn above the bar.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Draw a swatch of the no data color after the bar, with the given label.
+    pub fn no_data(mut self, label: &str) -> Self {
+        self.no_data = Some(label.to_owned());
+        self
+    }
+
+    /// Set the color of the no data swatch, `#cccccc` by default.
+    pub fn no_data_color(mut self, color: RGB<u8>) -> Self {
+        self.no_data_color = color;
+        self
+    }
+
+    /// Format the breaks with a fixed number of decimals (by default, the shortest
+    /// representation of each value is used).
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Set a suffix appended to the formatted breaks, such as `" %"` or `"°C"`.
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_owned();
+        self
+    }
+
+    /// Use custom labels instead of the formatted breaks, the label `i` being drawn
+    /// at the break `i`. The missing labels are left empty and the extra ones are ignored.
+    pub fn labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Set the length (along its direction) and the thickness of the bar, in pixels
+    /// (240 by 16 by default).
+    pub fn size(mut self, length: f64, thickness: f64) -> Self {
+        self.length = length;
+        self.thickness = thickness;
+        self
+    }
+
+    /// The labels drawn at each break.
+    fn tick_labels(&self) -> Vec<String> {
+        (0..self.breaks.len())
+            .map(|i| match &self.labels {
+                Some(labels) => labels.get(i).cloned().unwrap_or_default(),
+                None => match self.decimals {
+                    Some(decimals) => format!("{:.*}{}", decimals, self.breaks[i], self.unit),
+                    None => format!("{}{}", self.breaks[i], self.unit),
+                },
+            })
+            .collect()
+    }
+
+    /// Render the legend as a standalone SVG document.
+    pub fn to_svg(&self) -> String {
+        let labels = self.tick_labels();
+        let label_width = labels.iter().map(|l| text_width(l)).fold(0., f64::max);
+        let no_data_width = self.no_data.as_deref().map_or(0., text_width);
+        let title_width = self.title.as_deref().map_or(0., text_width);
+        let top = MARGIN
+            + if self.title.is_some() {
+                TITLE_HEIGHT
+            } else {
+                0.
+            };
+        let (n, length, thickness) = (self.colors.len(), self.length, self.thickness);
+        let step = length / n as f64;
+        let horizontal = self.orientation == Orientation::Horizontal;
+
+        // Origin of the bar, and position of each break along it.
+        let (x, y) = if horizontal {
+            (MARGIN + label_width / 2., top)
+        } else {
+            (MARGIN, top + FONT_SIZE / 2.)
+        };
+        let position = |i: usize| {
+            if horizontal {
+                x + i as f64 * step
+            } else {
+                y + length - i as f64 * step
+            }
+        };
+        let (width, height) = if horizontal {
+            let mut width = x + length + label_width / 2.;
+            if self.no_data.is_some() {
+                width += GAP + no_data_width.max(thickness);
+            }
+            let height = y + thickness + TICK_LENGTH + FONT_SIZE * 1.5;
+            (width.max(MARGIN + title_width) + MARGIN, height + MARGIN)
+        } else {
+            let mut height = y + length + FONT_SIZE / 2.;
+            if self.no_data.is_some() {
+                height = height.max(y + length + GAP + thickness);
+            }
+            let labels_width = label_width.max(no_data_width);
+            let width = x + thickness + TICK_LENGTH + GAP / 2. + labels_width;
+            (width.max(MARGIN + title_width) + MARGIN, height + MARGIN)
+        };
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"{}\">",
+            px(FONT_SIZE),
+            w = px(width),
+            h = px(height)
+        )
+        .unwrap();
+        if let Some(title) = &self.title {
+            writeln!(
+                out,
+                "  <text x=\"{}\" y=\"{}\" font-weight=\"bold\">{}</text>",
+                px(MARGIN),
+                px(MARGIN + FONT_SIZE),
+                escape_xml(title)
+            )
+            .unwrap();
+        }
+
+        // Bar.
+        let (bar_width, bar_height) = if horizontal {
+            (length, thickness)
+        } else {
+            (thickness, length)
+        };
+        match self.style {
+            BarStyle::Stepped => {
+                for (i, &color) in self.colors.iter().enumerate() {
+                    let (rect_x, rect_y, w, h) = if horizontal {
+                        (position(i), y, step, thickness)
+                    } else {
+                        (x, position(i + 1), thickness, step)
+                    };
+                    writeln!(
+                        out,
+                        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                        px(rect_x),
+                        px(rect_y),
+                        px(w),
+                        px(h),
+                        to_hex(color)
+                    )
+                    .unwrap();
+                }
+            }
+            BarStyle::Continuous => {
+                let id = self.gradient_id();
+                let direction = if horizontal {
+                    "x1=\"0\" y1=\"0\" x2=\"1\" y2=\"0\""
+                } else {
+                    "x1=\"0\" y1=\"1\" x2=\"0\" y2=\"0\""
+                };
+                writeln!(out, "  <defs>").unwrap();
+                writeln!(out, "    <linearGradient id=\"{}\" {}>", id, direction).unwrap();
+                for (i, &color) in self.colors.iter().enumerate() {
+                    writeln!(
+                        out,
+                        "      <stop offset=\"{}\" stop-color=\"{}\"/>",
+                        px((i as f64 + 0.5) / n as f64),
+                        to_hex(color)
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "    </linearGradient>").unwrap();
+                writeln!(out, "  </defs>").unwrap();
+                writeln!(
+                    out,
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"url(#{})\"/>",
+                    px(x),
+                    px(y),
+                    px(bar_width),
+                    px(bar_height),
+                    id
+                )
+                .unwrap();
+            }
+        }
+
+        // Ticks and labels.
+        for (i, label) in labels.iter().enumerate() {
+            let p = position(i);
+            let (x1, y1, x2, y2, text_x, text_y, anchor) = if horizontal {
+                let bottom = y + thickness;
+                let text_y = bottom + TICK_LENGTH + FONT_SIZE;
+                (p, bottom, p, bottom + TICK_LENGTH, p, text_y, "middle")
+            } else {
+                let right = x + thickness;
+                let text_x = right + TICK_LENGTH + GAP / 2.;
+                (
+                    right,
+                    p,
+                    right + TICK_LENGTH,
+                    p,
+                    text_x,
+                    p + FONT_SIZE / 3.,
+                    "start",
+                )
+            };
+            writeln!(
+                out,
+                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#000000\"/>",
+                px(x1),
+                px(y1),
+                px(x2),
+                px(y2)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"{}\">{}</text>",
+                px(text_x),
+                px(text_y),
+                anchor,
+                escape_xml(label)
+            )
+            .unwrap();
+        }
+
+        // No data swatch.
+        if let Some(label) = &self.no_data {
+            let (rect_x, rect_y, text_x, text_y, anchor) = if horizontal {
+                let rect_x = x
+                    + length
+                    + label_width / 2.
+                    + GAP
+                    + (no_data_width / 2. - thickness / 2.).max(0.);
+                let text_y = y + thickness + TICK_LENGTH + FONT_SIZE;
+                (rect_x, y, rect_x + thickness / 2., text_y, "middle")
+            } else {
+                let rect_y = y + length + GAP;
+                let text_x = x + thickness + TICK_LENGTH + GAP / 2.;
+                let text_y = rect_y + thickness / 2. + FONT_SIZE / 3.;
+                (x, rect_y, text_x, text_y, "start")
+            };
+            writeln!(
+                out,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{t}\" height=\"{t}\" fill=\"{}\"/>",
+                px(rect_x),
+                px(rect_y),
+                to_hex(self.no_data_color),
+                t = px(thickness)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "  <text x=\"{}\" y=\"{}\" text-anchor=\"{}\">{}</text>",
+                px(text_x),
+                px(text_y),
+                anchor,
+                escape_xml(label)
+            )
+            .unwrap();
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Identifier of the gradient, made of the orientation and the colors so that
+    /// different legends can be embedded in the same page, like
+    /// `colorbrewer-h-deebf79ecae13182bd`.
+    fn gradient_id(&self) -> String {
+        let mut id = match self.orientation {
+            Orientation::Horizontal => String::from("colorbrewer-h-"),
+            Orientation::Vertical => String::from("colorbrewer-v-"),
+        };
+        for &color in &self.colors {
+            id.push_str(&to_hex(color)[1..]);
+        }
+        id
+    }
+}
+
+/// Render `colors` as a row of square swatches of `size` pixels.
+///
+/// ```rust
+/// use colorbrewer::legend::swatches;
+/// use colorbrewer::Palette;
+///
+/// let svg = swatches(Palette::Set2.colors(3).unwrap(), 20.);
+/// assert!(svg.contains("<rect x=\"40\" y=\"0\" width=\"20\" height=\"20\" fill=\"#8da0cb\"/>"));
+/// ```
+pub fn swatches(colors: &[RGB<u8>], size: f64) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+        w = px(size * colors.len() as f64),
+        h = px(size)
+    )
+    .unwrap();
+    for (i, &color) in colors.iter().enumerate() {
+        writeln!(
+            out,
+            "  <rect x=\"{}\" y=\"0\" width=\"{s}\" height=\"{s}\" fill=\"{}\"/>",
+            px(size * i as f64),
+            to_hex(color),
+            s = px(size)
+        )
+        .unwrap();
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Approximate width of `text`, in pixels.
+fn text_width(text: &str) -> f64 {
+    text.chars().count() as f64 * CHAR_WIDTH
+}
+
+/// Format a coordinate with at most two decimals.
+fn px(value: f64) -> String {
+    format!("{}", (value * 100.).round() / 100.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels() {
+        let legend = Legend::new(Palette::Blues, 3, &[0., 1. / 3., 0.5, 1.]).unwrap();
+        assert_eq!(
+            legend.clone().decimals(2).unit("%").tick_labels(),
+            vec!["0.00%", "0.33%", "0.50%", "1.00%"]
+        );
+        assert_eq!(
+            legend.labels(vec!["low".to_owned()]).tick_labels(),
+            vec!["low", "", "", ""]
+        );
+        assert!(Legend::new(Palette::Blues, 3, &[0., 1.]).is_err());
+    }
+
+    #[test]
+    fn test_stepped() {
+        let svg = Legend::new(Palette::Blues, 3, &[0., 10., 20., 30.])
+            .unwrap()
+            .title("A & B")
+            .no_data("n/a")
+            .to_svg();
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        assert_eq!(svg.matches("<line ").count(), 4);
+        assert!(svg.contains(">A &amp; B</text>"));
+        assert!(svg.contains("fill=\"#deebf7\""));
+        assert!(svg.contains("fill=\"#cccccc\""));
+        assert!(svg.contains(">n/a</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_continuous_vertical() {
+        let svg = Legend::new(Palette::RdBu, 5, &[-2., -1., 0., 1., 2., 3.])
+            .unwrap()
+            .orientation(Orientation::Vertical)
+            .style(BarStyle::Continuous)
+            .size(100., 10.)
+            .to_svg();
+        assert_eq!(svg.matches("<stop ").count(), 5);
+        assert_eq!(svg.matches("<rect ").count(), 1);
+        assert!(svg.contains("<stop offset=\"0.1\" stop-color=\"#ca0020\"/>"));
+        assert!(svg.contains("x1=\"0\" y1=\"1\" x2=\"0\" y2=\"0\""));
+        // The first break is at the bottom of the bar, the last one at its top.
+        assert!(svg.contains("<line x1=\"18\" y1=\"114\" x2=\"22\" y2=\"114\""));
+        assert!(svg.contains("<line x1=\"18\" y1=\"14\" x2=\"22\" y2=\"14\""));
+    }
+
+    #[test]
+    fn test_gradient_id() {
+        let legend = Legend::new(Palette::Blues, 3, &[0., 1., 2., 3.])
+            .unwrap()
+            .style(BarStyle::Continuous);
+        let horizontal = legend.clone().to_svg();
+        let vertical = legend.orientation(Orientation::Vertical).to_svg();
+        assert!(horizontal.contains("id=\"colorbrewer-h-deebf79ecae13182bd\""));
+        assert!(horizontal.contains("fill=\"url(#colorbrewer-h-deebf79ecae13182bd)\""));
+        assert!(vertical.contains("id=\"colorbrewer-v-deebf79ecae13182bd\""));
+    }
+}
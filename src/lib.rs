@@ -25,14 +25,278 @@
 //! let ramp = get_color_ramp(blue_pal, 3);
 //! ```
 //! <br>
+//! The name of a palette is given back by `Palette::name` and its `Display` implementation.
+//! <br>
+//! User-supplied names can be parsed with `Palette::parse_lenient`, which ignores the case,
+//! accepts "gray" for "grey" and a `_r` suffix asking for the colors in reverse order:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let parsed = Palette::parse_lenient("rdylbu_r").unwrap();
+//! assert_eq!(parsed.palette, Palette::RdYlBu);
+//! assert!(parsed.reversed);
+//! ```
+//! <br>
+//! Parsing an unknown name returns an `Error` suggesting the closest palette name, and
+//! `try_get_color_ramp` returns an `Error` reporting the valid number of colors
+//! when no ramp is defined for the requested number of colors:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let err = "Spectrl".parse::<Palette>().unwrap_err();
+//! assert_eq!(err.to_string(), "unknown palette \"Spectrl\" (did you mean \"Spectral\"?)");
+//! let err = try_get_color_ramp(Palette::Accent, 9).unwrap_err();
+//! assert_eq!(err.to_string(), "palette Accent has no color ramp with 9 colors (expected 3 to 8 colors)");
+//! ```
+//! <br>
+//! `get_color_ramp` allocates a new `Vec` on each call. When performance matters,
+//! the colors can be borrowed from the static ColorBrewer tables with `Palette::colors`:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let colors: &'static [rgb::RGB<u8>] = Palette::Oranges.colors(3).unwrap();
+//! assert_eq!(colors[2], rgb::RGB { r: 230, g: 85, b: 13 });
+//! ```
+//! <br>
+//! Each `Palette` also exposes some metadata: its scheme type (sequential, diverging
+//! or qualitative), the range of valid number of colors and the ColorBrewer properties
+//! (colorblind safe, print friendly, etc.) of each of its color ramps.
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! assert_eq!(Palette::RdBu.scheme_type(), SchemeType::Diverging);
+//! assert_eq!(Palette::Accent.max_classes(), 8);
+//! let properties = Palette::RdBu.properties(5).unwrap();
+//! assert_eq!(properties.colorblind_safe, Suitability::Yes);
+//! ```
+//! <br>
+//! All the palettes can be listed with `Palette::all` or `Palette::iter`, and selected
+//! according to their metadata with `Palette::filter`:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let palettes: Vec<Palette> = Palette::filter()
+//!     .kind(SchemeType::Diverging)
+//!     .classes(7)
+//!     .colorblind_safe()
+//!     .palettes();
+//! assert!(palettes.contains(&Palette::PuOr));
+//! ```
+//! <br>
+//! ColorBrewer only defines ramps from 3 to 9, 11 or 12 colors. A continuous `Colormap`,
+//! interpolating between the colors of the largest ramp of a palette (in sRGB, linear RGB,
+//! CIELAB or OKLab), can be used to get the color of any value or any number of colors:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let colormap = Palette::YlOrRd.colormap(Interpolation::Oklab);
+//! let color = colormap.eval(0.42);
+//! let colors = colormap.sample(20);
+//! ```
+//! <br>
+//! The `ramp` function builds a color ramp with some options, such as reversing
+//! the colors or taking them from a sub-interval of the palette:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let blue_to_red = ramp(Palette::RdBu, 7).reversed().build().unwrap();
+//! let dark_blues = ramp(Palette::Blues, 4).interval(0.5, 1.).build().unwrap();
+//! ```
+//! <br>
+//! The `classification` module computes the class breaks of numeric values (equal interval,
+//! quantile, Jenks natural breaks, standard deviation, geometric progression and head/tail
+//! breaks), optionally checking that the number of classes is valid for a palette:
+//!
+//! ```rust
+//! use colorbrewer::classification::{palette_breaks, Method};
+//! use colorbrewer::*;
+//!
+//! let values = [1., 2., 4., 5., 7., 8., 9., 10., 21., 25.];
+//! let breaks = palette_breaks(Palette::Reds, &values, 3, Method::Jenks).unwrap();
+//! assert_eq!(breaks, vec![1., 5., 10., 25.]);
+//! ```
+//! <br>
+//! A `Classifier` then maps values to the color of their class, with configurable
+//! colors for the missing values and the values outside of the breaks:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let classifier = Classifier::new(Palette::Reds, 3, vec![1., 5., 10., 25.]).unwrap();
+//! let colors = classifier.colors_for(&[3., 12., f64::NAN]);
+//! ```
+//! <br>
+//! For diverging palettes, a `DivergingScale` pins the central color of the palette
+//! to a midpoint value, discretely or with continuous interpolation:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let scale = DivergingScale::discrete(Palette::PuOr, 7, -10., 0., 40.).unwrap();
+//! assert_eq!(scale.color_for(0.), Palette::PuOr.colors(7).unwrap()[3]);
+//! ```
+//! <br>
+//! The `cvd` module simulates color vision deficiencies (protanopia, deuteranopia and
+//! tritanopia, with the Machado, Viénot or Brettel models and a severity parameter),
+//! and checks whether the simulated colors of a ramp can still be told apart:
+//!
+//! ```rust
+//! use colorbrewer::cvd::{Deficiency, Model, Simulator};
+//! use colorbrewer::*;
+//!
+//! let simulator = Simulator::new(Deficiency::Protanopia, Model::Brettel, 0.8);
+//! let ramp = Palette::PuOr.ramp(5).unwrap();
+//! let simulated = ramp.simulate_cvd(&simulator);
+//! assert!(simulator.is_distinguishable(&ramp, 10.));
+//! ```
+//! <br>
+//! The `colorspace` module converts colors to and from linear sRGB, HSL, HSV, CIE XYZ,
+//! CIELAB/LCh, OKLab/OKLCh and (naive) CMYK, one color at a time or for a whole `Ramp`:
+//!
+//! ```rust
+//! use colorbrewer::colorspace::ColorSpace;
+//! use colorbrewer::*;
+//!
+//! let ramp = Palette::YlGnBu.ramp(5).unwrap();
+//! let lab = ramp.to_color_space(ColorSpace::Lab);
+//! assert!(lab[0][0] > lab[4][0]);
+//! assert_eq!(Ramp::from_color_space(ColorSpace::Lab, &lab), ramp);
+//! let cmyk = ramp.to_cmyk();
+//! ```
+//! <br>
+//! The `metrics` module computes perceptual color differences (CIE76, CIEDE2000 or
+//! OKLab ΔE) and reports how well the colors of a ramp can be told apart:
+//!
+//! ```rust
+//! use colorbrewer::metrics::DeltaE;
+//! use colorbrewer::Palette;
+//!
+//! let report = Palette::RdBu.report(7, DeltaE::Ciede2000).unwrap();
+//! assert!(report.min_adjacent > 5.);
+//! assert!(report.lightness_asymmetry.unwrap() < 10.);
+//! ```
+//! <br>
+//! The `format` module converts colors to and from `#rrggbb` hexadecimal strings,
+//! CSS `rgb(r, g, b)` strings, packed `0xRRGGBB` integers and normalized floats.
+//! The same conversions are available for a whole `Ramp`:
+//!
+//! ```rust
+//! use colorbrewer::*;
+//!
+//! let ramp = Palette::Oranges.ramp(3).unwrap();
+//! assert_eq!(ramp.to_hex(), vec!["#fee6ce", "#fdae6b", "#e6550d"]);
+//! let custom = Ramp::from_hex(&["#fee6ce", "#000000"]).unwrap();
+//! ```
+//! <br>
+//! The `export` module writes the palettes in the file formats of other tools,
+//! such as QGIS layer styles and color ramp libraries, OGC SLD styles, Mapbox GL
+//! style expressions, or GIMP, Adobe and GMT swatch files:
+//!
+//! ```rust
+//! use colorbrewer::export::qgis;
+//! use colorbrewer::*;
+//!
+//! let qml = qgis::graduated_style(Palette::PuBuGn, 3, "population", Some(&[0., 1e3, 1e4, 1e5]))
+//!     .unwrap();
+//! let library = qgis::color_ramp_library();
+//!
+//! use colorbrewer::export::mapbox;
+//! let fill_color = mapbox::step_expression(Palette::PuBuGn, 3, "population", &[0., 1e3, 1e4, 1e5])
+//!     .unwrap();
+//!
+//! use colorbrewer::export::{ase, gpl};
+//! let swatches = ase::library();
+//! let gimp_palette = gpl::write_palette(Palette::PuBuGn, 5).unwrap();
+//! ```
+//! <br>
+//! The `legend` module renders map legends as SVG, horizontal or vertical, with
+//! stepped or continuous bars:
+//!
+//! ```rust
+//! use colorbrewer::legend::{BarStyle, Legend};
+//! use colorbrewer::*;
+//!
+//! let svg = Legend::new(Palette::GnBu, 4, &[0., 0.25, 0.5, 0.75, 1.])
+//!     .unwrap()
+//!     .title("Share")
+//!     .style(BarStyle::Continuous)
+//!     .to_svg();
+//! ```
+//! <br>
+//! The `ansi` module renders colors in the terminal as 24-bit or 256 color ANSI escape
+//! sequences, and colors text according to a value, for example in heat colored tables:
+//!
+//! ```rust
+//! use colorbrewer::ansi::{swatches, ColorMode, Painter};
+//! use colorbrewer::*;
+//!
+//! let mode = ColorMode::detect().unwrap_or_default();
+//! println!("{}", swatches(Palette::Spectral.colors(9).unwrap(), 3, mode));
+//! let painter = Painter::continuous(Palette::OrRd.colormap(Interpolation::Oklab), 0., 100.);
+//! println!("{}", painter.paint(" 42 ", 42.));
+//! ```
+//! <br>
+//! With the optional `serde` feature, `Palette` (serialized as its name), its metadata
+//! and `Ramp` (the owned color ramp returned by `Palette::ramp`) implement
+//! `Serialize` and `Deserialize`. See the `serde_colors` module for the available
+//! representations of the colors.
+//! <br>
+//! With the optional `image` feature, the `raster` module draws the ramps as
+//! `image::RgbImage` strips or grids, and a contact sheet of all the palettes
+//! at all their numbers of colors.
+//! <br>
+//! With the optional `plotters` feature, a `Colormap` implements the `ColorMap` trait
+//! of the `plotters` crate (for heatmaps and surfaces), and the `plotting` module
+//! converts the colors to `plotters` colors and cycles through the colors of a palette
+//! to color the series of a chart.
+//! <br>
 //! Colors are described by their hexadecimal code.<br>
 //! These color specifications and designs are developed by Cynthia Brewer (http://colorbrewer2.org/).
 //!
 
+pub mod ansi;
+pub mod classification;
+mod classifier;
+pub mod colorspace;
+mod continuous;
+pub mod cvd;
+mod data;
+mod diverging;
+mod error;
+pub mod export;
+mod filter;
+pub mod format;
+pub mod legend;
+mod metadata;
+pub mod metrics;
+mod parse;
+#[cfg(feature = "plotters")]
+pub mod plotting;
+mod ramp;
+#[cfg(feature = "image")]
+pub mod raster;
+#[cfg(feature = "serde")]
+pub mod serde_colors;
+
+pub use classifier::{Classifier, OutOfRange};
+pub use continuous::{Colormap, Interpolation};
+pub use diverging::DivergingScale;
+pub use error::Error;
+pub use filter::PaletteFilter;
+pub use metadata::{Properties, SchemeType, Suitability};
+pub use parse::ParsedPalette;
+pub use ramp::{ramp, Ramp, RampBuilder};
 use rgb::RGB;
 
 /// Available color palettes
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Palette {
     YlGn,
     YlGnBu,
@@ -72,7 +336,7 @@ pub enum Palette {
 }
 
 impl std::str::FromStr for Palette {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -111,7 +375,186 @@ impl std::str::FromStr for Palette {
             "Set1" => Ok(Palette::Set1),
             "Set2" => Ok(Palette::Set2),
             "Set3" => Ok(Palette::Set3),
-            _ => Err("not a valid value"),
+            _ => Err(Error::unknown_palette(s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Palette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// All the palettes, in the order of the `Palette` enum.
+const PALETTES: [Palette; 35] = [
+    Palette::YlGn,
+    Palette::YlGnBu,
+    Palette::GnBu,
+    Palette::BuGn,
+    Palette::PuBuGn,
+    Palette::PuBu,
+    Palette::BuPu,
+    Palette::RdPu,
+    Palette::PuRd,
+    Palette::OrRd,
+    Palette::YlOrRd,
+    Palette::YlOrBr,
+    Palette::Purples,
+    Palette::Blues,
+    Palette::Greens,
+    Palette::Oranges,
+    Palette::Reds,
+    Palette::Greys,
+    Palette::PuOr,
+    Palette::BrBG,
+    Palette::PRGn,
+    Palette::PiYG,
+    Palette::RdBu,
+    Palette::RdGy,
+    Palette::RdYlBu,
+    Palette::Spectral,
+    Palette::RdYlGn,
+    Palette::Accent,
+    Palette::Dark2,
+    Palette::Paired,
+    Palette::Pastel1,
+    Palette::Pastel2,
+    Palette::Set1,
+    Palette::Set2,
+    Palette::Set3,
+];
+
+impl Palette {
+    /// All the available palettes.
+    ///
+    /// ```rust
+    /// use colorbrewer::Palette;
+    ///
+    /// assert_eq!(Palette::all().len(), 35);
+    /// assert_eq!(Palette::all()[0], Palette::YlGn);
+    /// ```
+    pub fn all() -> &'static [Palette] {
+        &PALETTES
+    }
+
+    /// ColorBrewer name of the palette, as accepted by its `FromStr` implementation.
+    ///
+    /// ```rust
+    /// use colorbrewer::Palette;
+    ///
+    /// assert_eq!(Palette::RdYlBu.name(), "RdYlBu");
+    /// assert_eq!(Palette::RdYlBu.to_string(), "RdYlBu");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Palette::YlGn => "YlGn",
+            Palette::YlGnBu => "YlGnBu",
+            Palette::GnBu => "GnBu",
+            Palette::BuGn => "BuGn",
+            Palette::PuBuGn => "PuBuGn",
+            Palette::PuBu => "PuBu",
+            Palette::BuPu => "BuPu",
+            Palette::RdPu => "RdPu",
+            Palette::PuRd => "PuRd",
+            Palette::OrRd => "OrRd",
+            Palette::YlOrRd => "YlOrRd",
+            Palette::YlOrBr => "YlOrBr",
+            Palette::Purples => "Purples",
+            Palette::Blues => "Blues",
+            Palette::Greens => "Greens",
+            Palette::Oranges => "Oranges",
+            Palette::Reds => "Reds",
+            Palette::Greys => "Greys",
+            Palette::PuOr => "PuOr",
+            Palette::BrBG => "BrBG",
+            Palette::PRGn => "PRGn",
+            Palette::PiYG => "PiYG",
+            Palette::RdBu => "RdBu",
+            Palette::RdGy => "RdGy",
+            Palette::RdYlBu => "RdYlBu",
+            Palette::Spectral => "Spectral",
+            Palette::RdYlGn => "RdYlGn",
+            Palette::Accent => "Accent",
+            Palette::Dark2 => "Dark2",
+            Palette::Paired => "Paired",
+            Palette::Pastel1 => "Pastel1",
+            Palette::Pastel2 => "Pastel2",
+            Palette::Set1 => "Set1",
+            Palette::Set2 => "Set2",
+            Palette::Set3 => "Set3",
+        }
+    }
+
+    /// Iterate over all the available palettes.
+    pub fn iter() -> impl Iterator<Item = Palette> {
+        PALETTES.iter().copied()
+    }
+
+    /// Get the colors of this palette for the requested number of colors,
+    /// as a slice borrowed from the static ColorBrewer tables (no allocation is performed).
+    /// Return `None` if there is no color ramp defined for this value of `nb_value`.
+    ///
+    /// ```rust
+    /// use colorbrewer::Palette;
+    ///
+    /// let colors = Palette::Greens.colors(3).unwrap();
+    /// assert_eq!(colors[0], rgb::RGB { r: 229, g: 245, b: 224 });
+    /// assert_eq!(Palette::Greens.colors(10), None);
+    /// ```
+    pub fn colors(&self, nb_value: u32) -> Option<&'static [RGB<u8>]> {
+        let index = nb_value.checked_sub(3)? as usize;
+        self.ramps().get(index).copied()
+    }
+
+    /// Get the colors of this palette for the requested number of colors,
+    /// as a slice borrowed from the static ColorBrewer tables.
+    /// Return an [`Error::InvalidClassCount`] reporting the valid number of colors
+    /// if there is no color ramp defined for this value of `nb_value`.
+    pub fn try_colors(&self, nb_value: u32) -> Result<&'static [RGB<u8>], Error> {
+        self.colors(nb_value)
+            .ok_or_else(|| Error::invalid_class_count(*self, nb_value))
+    }
+
+    /// All the ramps defined for this palette, from the one with 3 colors
+    /// to the one with the maximum number of colors.
+    fn ramps(&self) -> &'static [&'static [RGB<u8>]] {
+        match self {
+            Palette::YlGn => data::YLGN,
+            Palette::YlGnBu => data::YLGNBU,
+            Palette::GnBu => data::GNBU,
+            Palette::BuGn => data::BUGN,
+            Palette::PuBuGn => data::PUBUGN,
+            Palette::PuBu => data::PUBU,
+            Palette::BuPu => data::BUPU,
+            Palette::RdPu => data::RDPU,
+            Palette::PuRd => data::PURD,
+            Palette::OrRd => data::ORRD,
+            Palette::YlOrRd => data::YLORRD,
+            Palette::YlOrBr => data::YLORBR,
+            Palette::Purples => data::PURPLES,
+            Palette::Blues => data::BLUES,
+            Palette::Greens => data::GREENS,
+            Palette::Oranges => data::ORANGES,
+            Palette::Reds => data::REDS,
+            Palette::Greys => data::GREYS,
+            Palette::PuOr => data::PUOR,
+            Palette::BrBG => data::BRBG,
+            Palette::PRGn => data::PRGN,
+            Palette::PiYG => data::PIYG,
+            Palette::RdBu => data::RDBU,
+            Palette::RdGy => data::RDGY,
+            Palette::RdYlBu => data::RDYLBU,
+            Palette::Spectral => data::SPECTRAL,
+            Palette::RdYlGn => data::RDYLGN,
+            Palette::Accent => data::ACCENT,
+            Palette::Dark2 => data::DARK2,
+            Palette::Paired => data::PAIRED,
+            Palette::Pastel1 => data::PASTEL1,
+            Palette::Pastel2 => data::PASTEL2,
+            Palette::Set1 => data::SET1,
+            Palette::Set2 => data::SET2,
+            Palette::Set3 => data::SET3,
         }
     }
 }
@@ -119,8983 +562,48 @@ impl std::str::FromStr for Palette {
 /// Function to get the requested color ramp
 /// according to a given name and number of colors.
 /// Return `None` if there is no color ramp defined for this value of `nb_value`.
+///
+/// This allocates a new `Vec` on each call, see [`Palette::colors`] to borrow
+/// the colors from the static tables instead.
 pub fn get_color_ramp(name: Palette, nb_value: u32) -> Option<Vec<RGB<u8>>> {
-    match name {
-        Palette::YlGn => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 185,
-                },
-                RGB {
-                    r: 173,
-                    g: 221,
-                    b: 142,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 194,
-                    g: 230,
-                    b: 153,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 35,
-                    g: 132,
-                    b: 67,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 194,
-                    g: 230,
-                    b: 153,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-                RGB {
-                    r: 0,
-                    g: 104,
-                    b: 55,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 163,
-                },
-                RGB {
-                    r: 173,
-                    g: 221,
-                    b: 142,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-                RGB {
-                    r: 0,
-                    g: 104,
-                    b: 55,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 163,
-                },
-                RGB {
-                    r: 173,
-                    g: 221,
-                    b: 142,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 132,
-                    b: 67,
-                },
-                RGB { r: 0, g: 90, b: 50 },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 229,
-                },
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 185,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 163,
-                },
-                RGB {
-                    r: 173,
-                    g: 221,
-                    b: 142,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 132,
-                    b: 67,
-                },
-                RGB { r: 0, g: 90, b: 50 },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 229,
-                },
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 185,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 163,
-                },
-                RGB {
-                    r: 173,
-                    g: 221,
-                    b: 142,
-                },
-                RGB {
-                    r: 120,
-                    g: 198,
-                    b: 121,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 132,
-                    b: 67,
-                },
-                RGB {
-                    r: 0,
-                    g: 104,
-                    b: 55,
-                },
-                RGB { r: 0, g: 69, b: 41 },
-            ]),
-            _ => None,
-        },
-        Palette::YlGnBu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 177,
-                },
-                RGB {
-                    r: 127,
-                    g: 205,
-                    b: 187,
-                },
-                RGB {
-                    r: 44,
-                    g: 127,
-                    b: 184,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 161,
-                    g: 218,
-                    b: 180,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 34,
-                    g: 94,
-                    b: 168,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 161,
-                    g: 218,
-                    b: 180,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 44,
-                    g: 127,
-                    b: 184,
-                },
-                RGB {
-                    r: 37,
-                    g: 52,
-                    b: 148,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
+    name.colors(nb_value).map(|colors| colors.to_vec())
+}
+
+/// Function to get the requested color ramp
+/// according to a given name and number of colors.
+/// Return an [`Error::InvalidClassCount`] reporting the valid number of colors
+/// if there is no color ramp defined for this value of `nb_value`.
+///
+/// ```rust
+/// use colorbrewer::*;
+///
+/// let err = try_get_color_ramp(Palette::Pastel2, 35).unwrap_err();
+/// assert_eq!(err.to_string(), "palette Pastel2 has no color ramp with 35 colors (expected 3 to 8 colors)");
+/// ```
+pub fn try_get_color_ramp(name: Palette, nb_value: u32) -> Result<Vec<RGB<u8>>, Error> {
+    name.try_colors(nb_value).map(|colors| colors.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{get_color_ramp, Palette};
+    use rgb::RGB;
+
+    #[test]
+    fn test_get_color_ramp() {
+        let ramp = get_color_ramp(Palette::Pastel2, 3);
+        assert_eq!(
+            ramp,
+            Some(vec![
                 RGB {
-                    r: 199,
-                    g: 233,
-                    b: 180,
+                    r: 179,
+                    g: 226,
+                    b: 205
                 },
                 RGB {
-                    r: 127,
+                    r: 253,
                     g: 205,
-                    b: 187,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 44,
-                    g: 127,
-                    b: 184,
-                },
-                RGB {
-                    r: 37,
-                    g: 52,
-                    b: 148,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 180,
-                },
-                RGB {
-                    r: 127,
-                    g: 205,
-                    b: 187,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 29,
-                    g: 145,
-                    b: 192,
-                },
-                RGB {
-                    r: 34,
-                    g: 94,
-                    b: 168,
-                },
-                RGB {
-                    r: 12,
-                    g: 44,
-                    b: 132,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 217,
-                },
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 177,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 180,
-                },
-                RGB {
-                    r: 127,
-                    g: 205,
-                    b: 187,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 29,
-                    g: 145,
-                    b: 192,
-                },
-                RGB {
-                    r: 34,
-                    g: 94,
-                    b: 168,
-                },
-                RGB {
-                    r: 12,
-                    g: 44,
-                    b: 132,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 217,
-                },
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 177,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 180,
-                },
-                RGB {
-                    r: 127,
-                    g: 205,
-                    b: 187,
-                },
-                RGB {
-                    r: 65,
-                    g: 182,
-                    b: 196,
-                },
-                RGB {
-                    r: 29,
-                    g: 145,
-                    b: 192,
-                },
-                RGB {
-                    r: 34,
-                    g: 94,
-                    b: 168,
-                },
-                RGB {
-                    r: 37,
-                    g: 52,
-                    b: 148,
-                },
-                RGB { r: 8, g: 29, b: 88 },
-            ]),
-            _ => None,
-        },
-        Palette::GnBu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 219,
-                },
-                RGB {
-                    r: 168,
-                    g: 221,
-                    b: 181,
-                },
-                RGB {
-                    r: 67,
-                    g: 162,
-                    b: 202,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 240,
-                    g: 249,
-                    b: 232,
-                },
-                RGB {
-                    r: 186,
-                    g: 228,
-                    b: 188,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 240,
-                    g: 249,
-                    b: 232,
-                },
-                RGB {
-                    r: 186,
-                    g: 228,
-                    b: 188,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 67,
-                    g: 162,
-                    b: 202,
-                },
-                RGB {
-                    r: 8,
-                    g: 104,
-                    b: 172,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 240,
-                    g: 249,
-                    b: 232,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 168,
-                    g: 221,
-                    b: 181,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 67,
-                    g: 162,
-                    b: 202,
-                },
-                RGB {
-                    r: 8,
-                    g: 104,
-                    b: 172,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 240,
-                    g: 249,
-                    b: 232,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 168,
-                    g: 221,
-                    b: 181,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 78,
-                    g: 179,
-                    b: 211,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-                RGB {
-                    r: 8,
-                    g: 88,
-                    b: 158,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 240,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 219,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 168,
-                    g: 221,
-                    b: 181,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 78,
-                    g: 179,
-                    b: 211,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-                RGB {
-                    r: 8,
-                    g: 88,
-                    b: 158,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 240,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 219,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 168,
-                    g: 221,
-                    b: 181,
-                },
-                RGB {
-                    r: 123,
-                    g: 204,
-                    b: 196,
-                },
-                RGB {
-                    r: 78,
-                    g: 179,
-                    b: 211,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-                RGB {
-                    r: 8,
-                    g: 104,
-                    b: 172,
-                },
-                RGB {
-                    r: 8,
-                    g: 64,
-                    b: 129,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::BuGn => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 249,
-                },
-                RGB {
-                    r: 153,
-                    g: 216,
-                    b: 201,
-                },
-                RGB {
-                    r: 44,
-                    g: 162,
-                    b: 95,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 178,
-                    g: 226,
-                    b: 226,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 178,
-                    g: 226,
-                    b: 226,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 44,
-                    g: 162,
-                    b: 95,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 204,
-                    g: 236,
-                    b: 230,
-                },
-                RGB {
-                    r: 153,
-                    g: 216,
-                    b: 201,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 44,
-                    g: 162,
-                    b: 95,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 204,
-                    g: 236,
-                    b: 230,
-                },
-                RGB {
-                    r: 153,
-                    g: 216,
-                    b: 201,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 65,
-                    g: 174,
-                    b: 118,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB { r: 0, g: 88, b: 36 },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 253,
-                },
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 249,
-                },
-                RGB {
-                    r: 204,
-                    g: 236,
-                    b: 230,
-                },
-                RGB {
-                    r: 153,
-                    g: 216,
-                    b: 201,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 65,
-                    g: 174,
-                    b: 118,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB { r: 0, g: 88, b: 36 },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 253,
-                },
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 249,
-                },
-                RGB {
-                    r: 204,
-                    g: 236,
-                    b: 230,
-                },
-                RGB {
-                    r: 153,
-                    g: 216,
-                    b: 201,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 164,
-                },
-                RGB {
-                    r: 65,
-                    g: 174,
-                    b: 118,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-                RGB { r: 0, g: 68, b: 27 },
-            ]),
-            _ => None,
-        },
-        Palette::PuBuGn => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 236,
-                    g: 226,
-                    b: 240,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 28,
-                    g: 144,
-                    b: 153,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 246,
-                    g: 239,
-                    b: 247,
-                },
-                RGB {
-                    r: 189,
-                    g: 201,
-                    b: 225,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 2,
-                    g: 129,
-                    b: 138,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 246,
-                    g: 239,
-                    b: 247,
-                },
-                RGB {
-                    r: 189,
-                    g: 201,
-                    b: 225,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 28,
-                    g: 144,
-                    b: 153,
-                },
-                RGB {
-                    r: 1,
-                    g: 108,
-                    b: 89,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 246,
-                    g: 239,
-                    b: 247,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 28,
-                    g: 144,
-                    b: 153,
-                },
-                RGB {
-                    r: 1,
-                    g: 108,
-                    b: 89,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 246,
-                    g: 239,
-                    b: 247,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 2,
-                    g: 129,
-                    b: 138,
-                },
-                RGB {
-                    r: 1,
-                    g: 100,
-                    b: 80,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 251,
-                },
-                RGB {
-                    r: 236,
-                    g: 226,
-                    b: 240,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 2,
-                    g: 129,
-                    b: 138,
-                },
-                RGB {
-                    r: 1,
-                    g: 100,
-                    b: 80,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 251,
-                },
-                RGB {
-                    r: 236,
-                    g: 226,
-                    b: 240,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 2,
-                    g: 129,
-                    b: 138,
-                },
-                RGB {
-                    r: 1,
-                    g: 108,
-                    b: 89,
-                },
-                RGB { r: 1, g: 70, b: 54 },
-            ]),
-            _ => None,
-        },
-        Palette::PuBu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 236,
-                    g: 231,
-                    b: 242,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 189,
-                    g: 201,
-                    b: 225,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 5,
-                    g: 112,
-                    b: 176,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 189,
-                    g: 201,
-                    b: 225,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-                RGB {
-                    r: 4,
-                    g: 90,
-                    b: 141,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 43,
-                    g: 140,
-                    b: 190,
-                },
-                RGB {
-                    r: 4,
-                    g: 90,
-                    b: 141,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 5,
-                    g: 112,
-                    b: 176,
-                },
-                RGB {
-                    r: 3,
-                    g: 78,
-                    b: 123,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 251,
-                },
-                RGB {
-                    r: 236,
-                    g: 231,
-                    b: 242,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 5,
-                    g: 112,
-                    b: 176,
-                },
-                RGB {
-                    r: 3,
-                    g: 78,
-                    b: 123,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 251,
-                },
-                RGB {
-                    r: 236,
-                    g: 231,
-                    b: 242,
-                },
-                RGB {
-                    r: 208,
-                    g: 209,
-                    b: 230,
-                },
-                RGB {
-                    r: 166,
-                    g: 189,
-                    b: 219,
-                },
-                RGB {
-                    r: 116,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 54,
-                    g: 144,
-                    b: 192,
-                },
-                RGB {
-                    r: 5,
-                    g: 112,
-                    b: 176,
-                },
-                RGB {
-                    r: 4,
-                    g: 90,
-                    b: 141,
-                },
-                RGB { r: 2, g: 56, b: 88 },
-            ]),
-            _ => None,
-        },
-        Palette::BuPu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 224,
-                    g: 236,
-                    b: 244,
-                },
-                RGB {
-                    r: 158,
-                    g: 188,
-                    b: 218,
-                },
-                RGB {
-                    r: 136,
-                    g: 86,
-                    b: 167,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 136,
-                    g: 65,
-                    b: 157,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 136,
-                    g: 86,
-                    b: 167,
-                },
-                RGB {
-                    r: 129,
-                    g: 15,
-                    b: 124,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 191,
-                    g: 211,
-                    b: 230,
-                },
-                RGB {
-                    r: 158,
-                    g: 188,
-                    b: 218,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 136,
-                    g: 86,
-                    b: 167,
-                },
-                RGB {
-                    r: 129,
-                    g: 15,
-                    b: 124,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 251,
-                },
-                RGB {
-                    r: 191,
-                    g: 211,
-                    b: 230,
-                },
-                RGB {
-                    r: 158,
-                    g: 188,
-                    b: 218,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 140,
-                    g: 107,
-                    b: 177,
-                },
-                RGB {
-                    r: 136,
-                    g: 65,
-                    b: 157,
-                },
-                RGB {
-                    r: 110,
-                    g: 1,
-                    b: 107,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 253,
-                },
-                RGB {
-                    r: 224,
-                    g: 236,
-                    b: 244,
-                },
-                RGB {
-                    r: 191,
-                    g: 211,
-                    b: 230,
-                },
-                RGB {
-                    r: 158,
-                    g: 188,
-                    b: 218,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 140,
-                    g: 107,
-                    b: 177,
-                },
-                RGB {
-                    r: 136,
-                    g: 65,
-                    b: 157,
-                },
-                RGB {
-                    r: 110,
-                    g: 1,
-                    b: 107,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 253,
-                },
-                RGB {
-                    r: 224,
-                    g: 236,
-                    b: 244,
-                },
-                RGB {
-                    r: 191,
-                    g: 211,
-                    b: 230,
-                },
-                RGB {
-                    r: 158,
-                    g: 188,
-                    b: 218,
-                },
-                RGB {
-                    r: 140,
-                    g: 150,
-                    b: 198,
-                },
-                RGB {
-                    r: 140,
-                    g: 107,
-                    b: 177,
-                },
-                RGB {
-                    r: 136,
-                    g: 65,
-                    b: 157,
-                },
-                RGB {
-                    r: 129,
-                    g: 15,
-                    b: 124,
-                },
-                RGB { r: 77, g: 0, b: 75 },
-            ]),
-            _ => None,
-        },
-        Palette::RdPu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 221,
-                },
-                RGB {
-                    r: 250,
-                    g: 159,
-                    b: 181,
-                },
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 138,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 235,
-                    b: 226,
-                },
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 185,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 174,
-                    g: 1,
-                    b: 126,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 235,
-                    b: 226,
-                },
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 185,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 138,
-                },
-                RGB {
-                    r: 122,
-                    g: 1,
-                    b: 119,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 235,
-                    b: 226,
-                },
-                RGB {
-                    r: 252,
-                    g: 197,
-                    b: 192,
-                },
-                RGB {
-                    r: 250,
-                    g: 159,
-                    b: 181,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 138,
-                },
-                RGB {
-                    r: 122,
-                    g: 1,
-                    b: 119,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 235,
-                    b: 226,
-                },
-                RGB {
-                    r: 252,
-                    g: 197,
-                    b: 192,
-                },
-                RGB {
-                    r: 250,
-                    g: 159,
-                    b: 181,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 221,
-                    g: 52,
-                    b: 151,
-                },
-                RGB {
-                    r: 174,
-                    g: 1,
-                    b: 126,
-                },
-                RGB {
-                    r: 122,
-                    g: 1,
-                    b: 119,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 243,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 221,
-                },
-                RGB {
-                    r: 252,
-                    g: 197,
-                    b: 192,
-                },
-                RGB {
-                    r: 250,
-                    g: 159,
-                    b: 181,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 221,
-                    g: 52,
-                    b: 151,
-                },
-                RGB {
-                    r: 174,
-                    g: 1,
-                    b: 126,
-                },
-                RGB {
-                    r: 122,
-                    g: 1,
-                    b: 119,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 243,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 221,
-                },
-                RGB {
-                    r: 252,
-                    g: 197,
-                    b: 192,
-                },
-                RGB {
-                    r: 250,
-                    g: 159,
-                    b: 181,
-                },
-                RGB {
-                    r: 247,
-                    g: 104,
-                    b: 161,
-                },
-                RGB {
-                    r: 221,
-                    g: 52,
-                    b: 151,
-                },
-                RGB {
-                    r: 174,
-                    g: 1,
-                    b: 126,
-                },
-                RGB {
-                    r: 122,
-                    g: 1,
-                    b: 119,
-                },
-                RGB {
-                    r: 73,
-                    g: 0,
-                    b: 106,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::PuRd => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 231,
-                    g: 225,
-                    b: 239,
-                },
-                RGB {
-                    r: 201,
-                    g: 148,
-                    b: 199,
-                },
-                RGB {
-                    r: 221,
-                    g: 28,
-                    b: 119,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 215,
-                    g: 181,
-                    b: 216,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 206,
-                    g: 18,
-                    b: 86,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 215,
-                    g: 181,
-                    b: 216,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 221,
-                    g: 28,
-                    b: 119,
-                },
-                RGB {
-                    r: 152,
-                    g: 0,
-                    b: 67,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 212,
-                    g: 185,
-                    b: 218,
-                },
-                RGB {
-                    r: 201,
-                    g: 148,
-                    b: 199,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 221,
-                    g: 28,
-                    b: 119,
-                },
-                RGB {
-                    r: 152,
-                    g: 0,
-                    b: 67,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 238,
-                    b: 246,
-                },
-                RGB {
-                    r: 212,
-                    g: 185,
-                    b: 218,
-                },
-                RGB {
-                    r: 201,
-                    g: 148,
-                    b: 199,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 206,
-                    g: 18,
-                    b: 86,
-                },
-                RGB {
-                    r: 145,
-                    g: 0,
-                    b: 63,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 244,
-                    b: 249,
-                },
-                RGB {
-                    r: 231,
-                    g: 225,
-                    b: 239,
-                },
-                RGB {
-                    r: 212,
-                    g: 185,
-                    b: 218,
-                },
-                RGB {
-                    r: 201,
-                    g: 148,
-                    b: 199,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 206,
-                    g: 18,
-                    b: 86,
-                },
-                RGB {
-                    r: 145,
-                    g: 0,
-                    b: 63,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 244,
-                    b: 249,
-                },
-                RGB {
-                    r: 231,
-                    g: 225,
-                    b: 239,
-                },
-                RGB {
-                    r: 212,
-                    g: 185,
-                    b: 218,
-                },
-                RGB {
-                    r: 201,
-                    g: 148,
-                    b: 199,
-                },
-                RGB {
-                    r: 223,
-                    g: 101,
-                    b: 176,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 206,
-                    g: 18,
-                    b: 86,
-                },
-                RGB {
-                    r: 152,
-                    g: 0,
-                    b: 67,
-                },
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 31,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::OrRd => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 232,
-                    b: 200,
-                },
-                RGB {
-                    r: 253,
-                    g: 187,
-                    b: 132,
-                },
-                RGB {
-                    r: 227,
-                    g: 74,
-                    b: 51,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 240,
-                    b: 217,
-                },
-                RGB {
-                    r: 253,
-                    g: 204,
-                    b: 138,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 31,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 240,
-                    b: 217,
-                },
-                RGB {
-                    r: 253,
-                    g: 204,
-                    b: 138,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 227,
-                    g: 74,
-                    b: 51,
-                },
-                RGB { r: 179, g: 0, b: 0 },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 240,
-                    b: 217,
-                },
-                RGB {
-                    r: 253,
-                    g: 212,
-                    b: 158,
-                },
-                RGB {
-                    r: 253,
-                    g: 187,
-                    b: 132,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 227,
-                    g: 74,
-                    b: 51,
-                },
-                RGB { r: 179, g: 0, b: 0 },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 240,
-                    b: 217,
-                },
-                RGB {
-                    r: 253,
-                    g: 212,
-                    b: 158,
-                },
-                RGB {
-                    r: 253,
-                    g: 187,
-                    b: 132,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 239,
-                    g: 101,
-                    b: 72,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 31,
-                },
-                RGB { r: 153, g: 0, b: 0 },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 236,
-                },
-                RGB {
-                    r: 254,
-                    g: 232,
-                    b: 200,
-                },
-                RGB {
-                    r: 253,
-                    g: 212,
-                    b: 158,
-                },
-                RGB {
-                    r: 253,
-                    g: 187,
-                    b: 132,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 239,
-                    g: 101,
-                    b: 72,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 31,
-                },
-                RGB { r: 153, g: 0, b: 0 },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 236,
-                },
-                RGB {
-                    r: 254,
-                    g: 232,
-                    b: 200,
-                },
-                RGB {
-                    r: 253,
-                    g: 212,
-                    b: 158,
-                },
-                RGB {
-                    r: 253,
-                    g: 187,
-                    b: 132,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 239,
-                    g: 101,
-                    b: 72,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 31,
-                },
-                RGB { r: 179, g: 0, b: 0 },
-                RGB { r: 127, g: 0, b: 0 },
-            ]),
-            _ => None,
-        },
-        Palette::YlOrRd => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 237,
-                    b: 160,
-                },
-                RGB {
-                    r: 254,
-                    g: 178,
-                    b: 76,
-                },
-                RGB {
-                    r: 240,
-                    g: 59,
-                    b: 32,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 178,
-                },
-                RGB {
-                    r: 254,
-                    g: 204,
-                    b: 92,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 178,
-                },
-                RGB {
-                    r: 254,
-                    g: 204,
-                    b: 92,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 240,
-                    g: 59,
-                    b: 32,
-                },
-                RGB {
-                    r: 189,
-                    g: 0,
-                    b: 38,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 178,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 118,
-                },
-                RGB {
-                    r: 254,
-                    g: 178,
-                    b: 76,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 240,
-                    g: 59,
-                    b: 32,
-                },
-                RGB {
-                    r: 189,
-                    g: 0,
-                    b: 38,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 178,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 118,
-                },
-                RGB {
-                    r: 254,
-                    g: 178,
-                    b: 76,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 252,
-                    g: 78,
-                    b: 42,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 177,
-                    g: 0,
-                    b: 38,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 255,
-                    g: 237,
-                    b: 160,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 118,
-                },
-                RGB {
-                    r: 254,
-                    g: 178,
-                    b: 76,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 252,
-                    g: 78,
-                    b: 42,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 177,
-                    g: 0,
-                    b: 38,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 255,
-                    g: 237,
-                    b: 160,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 118,
-                },
-                RGB {
-                    r: 254,
-                    g: 178,
-                    b: 76,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 252,
-                    g: 78,
-                    b: 42,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 189,
-                    g: 0,
-                    b: 38,
-                },
-                RGB {
-                    r: 128,
-                    g: 0,
-                    b: 38,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::YlOrBr => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 188,
-                },
-                RGB {
-                    r: 254,
-                    g: 196,
-                    b: 79,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 14,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 212,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 142,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 204,
-                    g: 76,
-                    b: 2,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 212,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 142,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 14,
-                },
-                RGB {
-                    r: 153,
-                    g: 52,
-                    b: 4,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 212,
-                },
-                RGB {
-                    r: 254,
-                    g: 227,
-                    b: 145,
-                },
-                RGB {
-                    r: 254,
-                    g: 196,
-                    b: 79,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 14,
-                },
-                RGB {
-                    r: 153,
-                    g: 52,
-                    b: 4,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 212,
-                },
-                RGB {
-                    r: 254,
-                    g: 227,
-                    b: 145,
-                },
-                RGB {
-                    r: 254,
-                    g: 196,
-                    b: 79,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 236,
-                    g: 112,
-                    b: 20,
-                },
-                RGB {
-                    r: 204,
-                    g: 76,
-                    b: 2,
-                },
-                RGB {
-                    r: 140,
-                    g: 45,
-                    b: 4,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 229,
-                },
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 188,
-                },
-                RGB {
-                    r: 254,
-                    g: 227,
-                    b: 145,
-                },
-                RGB {
-                    r: 254,
-                    g: 196,
-                    b: 79,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 236,
-                    g: 112,
-                    b: 20,
-                },
-                RGB {
-                    r: 204,
-                    g: 76,
-                    b: 2,
-                },
-                RGB {
-                    r: 140,
-                    g: 45,
-                    b: 4,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 229,
-                },
-                RGB {
-                    r: 255,
-                    g: 247,
-                    b: 188,
-                },
-                RGB {
-                    r: 254,
-                    g: 227,
-                    b: 145,
-                },
-                RGB {
-                    r: 254,
-                    g: 196,
-                    b: 79,
-                },
-                RGB {
-                    r: 254,
-                    g: 153,
-                    b: 41,
-                },
-                RGB {
-                    r: 236,
-                    g: 112,
-                    b: 20,
-                },
-                RGB {
-                    r: 204,
-                    g: 76,
-                    b: 2,
-                },
-                RGB {
-                    r: 153,
-                    g: 52,
-                    b: 4,
-                },
-                RGB {
-                    r: 102,
-                    g: 37,
-                    b: 6,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Purples => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 237,
-                    b: 245,
-                },
-                RGB {
-                    r: 188,
-                    g: 189,
-                    b: 220,
-                },
-                RGB {
-                    r: 117,
-                    g: 107,
-                    b: 177,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 242,
-                    g: 240,
-                    b: 247,
-                },
-                RGB {
-                    r: 203,
-                    g: 201,
-                    b: 226,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 106,
-                    g: 81,
-                    b: 163,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 242,
-                    g: 240,
-                    b: 247,
-                },
-                RGB {
-                    r: 203,
-                    g: 201,
-                    b: 226,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 117,
-                    g: 107,
-                    b: 177,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 143,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 242,
-                    g: 240,
-                    b: 247,
-                },
-                RGB {
-                    r: 218,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 188,
-                    g: 189,
-                    b: 220,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 117,
-                    g: 107,
-                    b: 177,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 143,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 242,
-                    g: 240,
-                    b: 247,
-                },
-                RGB {
-                    r: 218,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 188,
-                    g: 189,
-                    b: 220,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 128,
-                    g: 125,
-                    b: 186,
-                },
-                RGB {
-                    r: 106,
-                    g: 81,
-                    b: 163,
-                },
-                RGB {
-                    r: 74,
-                    g: 20,
-                    b: 134,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 252,
-                    g: 251,
-                    b: 253,
-                },
-                RGB {
-                    r: 239,
-                    g: 237,
-                    b: 245,
-                },
-                RGB {
-                    r: 218,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 188,
-                    g: 189,
-                    b: 220,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 128,
-                    g: 125,
-                    b: 186,
-                },
-                RGB {
-                    r: 106,
-                    g: 81,
-                    b: 163,
-                },
-                RGB {
-                    r: 74,
-                    g: 20,
-                    b: 134,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 252,
-                    g: 251,
-                    b: 253,
-                },
-                RGB {
-                    r: 239,
-                    g: 237,
-                    b: 245,
-                },
-                RGB {
-                    r: 218,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 188,
-                    g: 189,
-                    b: 220,
-                },
-                RGB {
-                    r: 158,
-                    g: 154,
-                    b: 200,
-                },
-                RGB {
-                    r: 128,
-                    g: 125,
-                    b: 186,
-                },
-                RGB {
-                    r: 106,
-                    g: 81,
-                    b: 163,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 143,
-                },
-                RGB {
-                    r: 63,
-                    g: 0,
-                    b: 125,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Blues => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 222,
-                    g: 235,
-                    b: 247,
-                },
-                RGB {
-                    r: 158,
-                    g: 202,
-                    b: 225,
-                },
-                RGB {
-                    r: 49,
-                    g: 130,
-                    b: 189,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 243,
-                    b: 255,
-                },
-                RGB {
-                    r: 189,
-                    g: 215,
-                    b: 231,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 33,
-                    g: 113,
-                    b: 181,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 243,
-                    b: 255,
-                },
-                RGB {
-                    r: 189,
-                    g: 215,
-                    b: 231,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 49,
-                    g: 130,
-                    b: 189,
-                },
-                RGB {
-                    r: 8,
-                    g: 81,
-                    b: 156,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 243,
-                    b: 255,
-                },
-                RGB {
-                    r: 198,
-                    g: 219,
-                    b: 239,
-                },
-                RGB {
-                    r: 158,
-                    g: 202,
-                    b: 225,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 49,
-                    g: 130,
-                    b: 189,
-                },
-                RGB {
-                    r: 8,
-                    g: 81,
-                    b: 156,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 243,
-                    b: 255,
-                },
-                RGB {
-                    r: 198,
-                    g: 219,
-                    b: 239,
-                },
-                RGB {
-                    r: 158,
-                    g: 202,
-                    b: 225,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 66,
-                    g: 146,
-                    b: 198,
-                },
-                RGB {
-                    r: 33,
-                    g: 113,
-                    b: 181,
-                },
-                RGB {
-                    r: 8,
-                    g: 69,
-                    b: 148,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 251,
-                    b: 255,
-                },
-                RGB {
-                    r: 222,
-                    g: 235,
-                    b: 247,
-                },
-                RGB {
-                    r: 198,
-                    g: 219,
-                    b: 239,
-                },
-                RGB {
-                    r: 158,
-                    g: 202,
-                    b: 225,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 66,
-                    g: 146,
-                    b: 198,
-                },
-                RGB {
-                    r: 33,
-                    g: 113,
-                    b: 181,
-                },
-                RGB {
-                    r: 8,
-                    g: 69,
-                    b: 148,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 251,
-                    b: 255,
-                },
-                RGB {
-                    r: 222,
-                    g: 235,
-                    b: 247,
-                },
-                RGB {
-                    r: 198,
-                    g: 219,
-                    b: 239,
-                },
-                RGB {
-                    r: 158,
-                    g: 202,
-                    b: 225,
-                },
-                RGB {
-                    r: 107,
-                    g: 174,
-                    b: 214,
-                },
-                RGB {
-                    r: 66,
-                    g: 146,
-                    b: 198,
-                },
-                RGB {
-                    r: 33,
-                    g: 113,
-                    b: 181,
-                },
-                RGB {
-                    r: 8,
-                    g: 81,
-                    b: 156,
-                },
-                RGB {
-                    r: 8,
-                    g: 48,
-                    b: 107,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Greens => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 224,
-                },
-                RGB {
-                    r: 161,
-                    g: 217,
-                    b: 155,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 233,
-                },
-                RGB {
-                    r: 186,
-                    g: 228,
-                    b: 179,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 233,
-                },
-                RGB {
-                    r: 186,
-                    g: 228,
-                    b: 179,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 233,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 192,
-                },
-                RGB {
-                    r: 161,
-                    g: 217,
-                    b: 155,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 49,
-                    g: 163,
-                    b: 84,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 237,
-                    g: 248,
-                    b: 233,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 192,
-                },
-                RGB {
-                    r: 161,
-                    g: 217,
-                    b: 155,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB { r: 0, g: 90, b: 50 },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 245,
-                },
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 224,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 192,
-                },
-                RGB {
-                    r: 161,
-                    g: 217,
-                    b: 155,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB { r: 0, g: 90, b: 50 },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 252,
-                    b: 245,
-                },
-                RGB {
-                    r: 229,
-                    g: 245,
-                    b: 224,
-                },
-                RGB {
-                    r: 199,
-                    g: 233,
-                    b: 192,
-                },
-                RGB {
-                    r: 161,
-                    g: 217,
-                    b: 155,
-                },
-                RGB {
-                    r: 116,
-                    g: 196,
-                    b: 118,
-                },
-                RGB {
-                    r: 65,
-                    g: 171,
-                    b: 93,
-                },
-                RGB {
-                    r: 35,
-                    g: 139,
-                    b: 69,
-                },
-                RGB {
-                    r: 0,
-                    g: 109,
-                    b: 44,
-                },
-                RGB { r: 0, g: 68, b: 27 },
-            ]),
-            _ => None,
-        },
-        Palette::Oranges => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 230,
-                    b: 206,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 107,
-                },
-                RGB {
-                    r: 230,
-                    g: 85,
-                    b: 13,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 237,
-                    b: 222,
-                },
-                RGB {
-                    r: 253,
-                    g: 190,
-                    b: 133,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 217,
-                    g: 71,
-                    b: 1,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 237,
-                    b: 222,
-                },
-                RGB {
-                    r: 253,
-                    g: 190,
-                    b: 133,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 230,
-                    g: 85,
-                    b: 13,
-                },
-                RGB {
-                    r: 166,
-                    g: 54,
-                    b: 3,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 237,
-                    b: 222,
-                },
-                RGB {
-                    r: 253,
-                    g: 208,
-                    b: 162,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 107,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 230,
-                    g: 85,
-                    b: 13,
-                },
-                RGB {
-                    r: 166,
-                    g: 54,
-                    b: 3,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 237,
-                    b: 222,
-                },
-                RGB {
-                    r: 253,
-                    g: 208,
-                    b: 162,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 107,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 241,
-                    g: 105,
-                    b: 19,
-                },
-                RGB {
-                    r: 217,
-                    g: 72,
-                    b: 1,
-                },
-                RGB {
-                    r: 140,
-                    g: 45,
-                    b: 4,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 245,
-                    b: 235,
-                },
-                RGB {
-                    r: 254,
-                    g: 230,
-                    b: 206,
-                },
-                RGB {
-                    r: 253,
-                    g: 208,
-                    b: 162,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 107,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 241,
-                    g: 105,
-                    b: 19,
-                },
-                RGB {
-                    r: 217,
-                    g: 72,
-                    b: 1,
-                },
-                RGB {
-                    r: 140,
-                    g: 45,
-                    b: 4,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 245,
-                    b: 235,
-                },
-                RGB {
-                    r: 254,
-                    g: 230,
-                    b: 206,
-                },
-                RGB {
-                    r: 253,
-                    g: 208,
-                    b: 162,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 107,
-                },
-                RGB {
-                    r: 253,
-                    g: 141,
-                    b: 60,
-                },
-                RGB {
-                    r: 241,
-                    g: 105,
-                    b: 19,
-                },
-                RGB {
-                    r: 217,
-                    g: 72,
-                    b: 1,
-                },
-                RGB {
-                    r: 166,
-                    g: 54,
-                    b: 3,
-                },
-                RGB {
-                    r: 127,
-                    g: 39,
-                    b: 4,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Reds => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 210,
-                },
-                RGB {
-                    r: 252,
-                    g: 146,
-                    b: 114,
-                },
-                RGB {
-                    r: 222,
-                    g: 45,
-                    b: 38,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 229,
-                    b: 217,
-                },
-                RGB {
-                    r: 252,
-                    g: 174,
-                    b: 145,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 203,
-                    g: 24,
-                    b: 29,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 229,
-                    b: 217,
-                },
-                RGB {
-                    r: 252,
-                    g: 174,
-                    b: 145,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 222,
-                    g: 45,
-                    b: 38,
-                },
-                RGB {
-                    r: 165,
-                    g: 15,
-                    b: 21,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 229,
-                    b: 217,
-                },
-                RGB {
-                    r: 252,
-                    g: 187,
-                    b: 161,
-                },
-                RGB {
-                    r: 252,
-                    g: 146,
-                    b: 114,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 222,
-                    g: 45,
-                    b: 38,
-                },
-                RGB {
-                    r: 165,
-                    g: 15,
-                    b: 21,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 254,
-                    g: 229,
-                    b: 217,
-                },
-                RGB {
-                    r: 252,
-                    g: 187,
-                    b: 161,
-                },
-                RGB {
-                    r: 252,
-                    g: 146,
-                    b: 114,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 239,
-                    g: 59,
-                    b: 44,
-                },
-                RGB {
-                    r: 203,
-                    g: 24,
-                    b: 29,
-                },
-                RGB {
-                    r: 153,
-                    g: 0,
-                    b: 13,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 245,
-                    b: 240,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 210,
-                },
-                RGB {
-                    r: 252,
-                    g: 187,
-                    b: 161,
-                },
-                RGB {
-                    r: 252,
-                    g: 146,
-                    b: 114,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 239,
-                    g: 59,
-                    b: 44,
-                },
-                RGB {
-                    r: 203,
-                    g: 24,
-                    b: 29,
-                },
-                RGB {
-                    r: 153,
-                    g: 0,
-                    b: 13,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 245,
-                    b: 240,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 210,
-                },
-                RGB {
-                    r: 252,
-                    g: 187,
-                    b: 161,
-                },
-                RGB {
-                    r: 252,
-                    g: 146,
-                    b: 114,
-                },
-                RGB {
-                    r: 251,
-                    g: 106,
-                    b: 74,
-                },
-                RGB {
-                    r: 239,
-                    g: 59,
-                    b: 44,
-                },
-                RGB {
-                    r: 203,
-                    g: 24,
-                    b: 29,
-                },
-                RGB {
-                    r: 165,
-                    g: 15,
-                    b: 21,
-                },
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 13,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Greys => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 240,
-                    g: 240,
-                    b: 240,
-                },
-                RGB {
-                    r: 189,
-                    g: 189,
-                    b: 189,
-                },
-                RGB {
-                    r: 99,
-                    g: 99,
-                    b: 99,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 204,
-                    g: 204,
-                    b: 204,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 82,
-                    g: 82,
-                    b: 82,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 204,
-                    g: 204,
-                    b: 204,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 99,
-                    g: 99,
-                    b: 99,
-                },
-                RGB {
-                    r: 37,
-                    g: 37,
-                    b: 37,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 189,
-                    g: 189,
-                    b: 189,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 99,
-                    g: 99,
-                    b: 99,
-                },
-                RGB {
-                    r: 37,
-                    g: 37,
-                    b: 37,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 189,
-                    g: 189,
-                    b: 189,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 115,
-                    g: 115,
-                    b: 115,
-                },
-                RGB {
-                    r: 82,
-                    g: 82,
-                    b: 82,
-                },
-                RGB {
-                    r: 37,
-                    g: 37,
-                    b: 37,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 240,
-                    g: 240,
-                    b: 240,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 189,
-                    g: 189,
-                    b: 189,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 115,
-                    g: 115,
-                    b: 115,
-                },
-                RGB {
-                    r: 82,
-                    g: 82,
-                    b: 82,
-                },
-                RGB {
-                    r: 37,
-                    g: 37,
-                    b: 37,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 240,
-                    g: 240,
-                    b: 240,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 189,
-                    g: 189,
-                    b: 189,
-                },
-                RGB {
-                    r: 150,
-                    g: 150,
-                    b: 150,
-                },
-                RGB {
-                    r: 115,
-                    g: 115,
-                    b: 115,
-                },
-                RGB {
-                    r: 82,
-                    g: 82,
-                    b: 82,
-                },
-                RGB {
-                    r: 37,
-                    g: 37,
-                    b: 37,
-                },
-                RGB { r: 0, g: 0, b: 0 },
-            ]),
-            _ => None,
-        },
-        Palette::PuOr => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 241,
-                    g: 163,
-                    b: 64,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 153,
-                    g: 142,
-                    b: 195,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 230,
-                    g: 97,
-                    b: 1,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 94,
-                    g: 60,
-                    b: 153,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 230,
-                    g: 97,
-                    b: 1,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 94,
-                    g: 60,
-                    b: 153,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 241,
-                    g: 163,
-                    b: 64,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 153,
-                    g: 142,
-                    b: 195,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 241,
-                    g: 163,
-                    b: 64,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 153,
-                    g: 142,
-                    b: 195,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 224,
-                    g: 130,
-                    b: 20,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 128,
-                    g: 115,
-                    b: 172,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 224,
-                    g: 130,
-                    b: 20,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 128,
-                    g: 115,
-                    b: 172,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 59,
-                    b: 8,
-                },
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 224,
-                    g: 130,
-                    b: 20,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 128,
-                    g: 115,
-                    b: 172,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-                RGB { r: 45, g: 0, b: 75 },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 59,
-                    b: 8,
-                },
-                RGB {
-                    r: 179,
-                    g: 88,
-                    b: 6,
-                },
-                RGB {
-                    r: 224,
-                    g: 130,
-                    b: 20,
-                },
-                RGB {
-                    r: 253,
-                    g: 184,
-                    b: 99,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 182,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 216,
-                    g: 218,
-                    b: 235,
-                },
-                RGB {
-                    r: 178,
-                    g: 171,
-                    b: 210,
-                },
-                RGB {
-                    r: 128,
-                    g: 115,
-                    b: 172,
-                },
-                RGB {
-                    r: 84,
-                    g: 39,
-                    b: 136,
-                },
-                RGB { r: 45, g: 0, b: 75 },
-            ]),
-            _ => None,
-        },
-        Palette::BrBG => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 216,
-                    g: 179,
-                    b: 101,
-                },
-                RGB {
-                    r: 245,
-                    g: 245,
-                    b: 245,
-                },
-                RGB {
-                    r: 90,
-                    g: 180,
-                    b: 172,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 97,
-                    b: 26,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 1,
-                    g: 133,
-                    b: 113,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 97,
-                    b: 26,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 245,
-                    g: 245,
-                    b: 245,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 1,
-                    g: 133,
-                    b: 113,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 216,
-                    g: 179,
-                    b: 101,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 90,
-                    g: 180,
-                    b: 172,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 216,
-                    g: 179,
-                    b: 101,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 245,
-                    g: 245,
-                    b: 245,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 90,
-                    g: 180,
-                    b: 172,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 191,
-                    g: 129,
-                    b: 45,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 53,
-                    g: 151,
-                    b: 143,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 191,
-                    g: 129,
-                    b: 45,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 245,
-                    g: 245,
-                    b: 245,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 53,
-                    g: 151,
-                    b: 143,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-            ]),
-            10 => Some(vec![
-                RGB { r: 84, g: 48, b: 5 },
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 191,
-                    g: 129,
-                    b: 45,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 53,
-                    g: 151,
-                    b: 143,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-                RGB { r: 0, g: 60, b: 48 },
-            ]),
-            11 => Some(vec![
-                RGB { r: 84, g: 48, b: 5 },
-                RGB {
-                    r: 140,
-                    g: 81,
-                    b: 10,
-                },
-                RGB {
-                    r: 191,
-                    g: 129,
-                    b: 45,
-                },
-                RGB {
-                    r: 223,
-                    g: 194,
-                    b: 125,
-                },
-                RGB {
-                    r: 246,
-                    g: 232,
-                    b: 195,
-                },
-                RGB {
-                    r: 245,
-                    g: 245,
-                    b: 245,
-                },
-                RGB {
-                    r: 199,
-                    g: 234,
-                    b: 229,
-                },
-                RGB {
-                    r: 128,
-                    g: 205,
-                    b: 193,
-                },
-                RGB {
-                    r: 53,
-                    g: 151,
-                    b: 143,
-                },
-                RGB {
-                    r: 1,
-                    g: 102,
-                    b: 94,
-                },
-                RGB { r: 0, g: 60, b: 48 },
-            ]),
-            _ => None,
-        },
-        Palette::PRGn => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 175,
-                    g: 141,
-                    b: 195,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 127,
-                    g: 191,
-                    b: 123,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 123,
-                    g: 50,
-                    b: 148,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 0,
-                    g: 136,
-                    b: 55,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 123,
-                    g: 50,
-                    b: 148,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 0,
-                    g: 136,
-                    b: 55,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 175,
-                    g: 141,
-                    b: 195,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 127,
-                    g: 191,
-                    b: 123,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 175,
-                    g: 141,
-                    b: 195,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 127,
-                    g: 191,
-                    b: 123,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 153,
-                    g: 112,
-                    b: 171,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 90,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 153,
-                    g: 112,
-                    b: 171,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 90,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-            ]),
-            10 => Some(vec![
-                RGB { r: 64, g: 0, b: 75 },
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 153,
-                    g: 112,
-                    b: 171,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 90,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-                RGB { r: 0, g: 68, b: 27 },
-            ]),
-            11 => Some(vec![
-                RGB { r: 64, g: 0, b: 75 },
-                RGB {
-                    r: 118,
-                    g: 42,
-                    b: 131,
-                },
-                RGB {
-                    r: 153,
-                    g: 112,
-                    b: 171,
-                },
-                RGB {
-                    r: 194,
-                    g: 165,
-                    b: 207,
-                },
-                RGB {
-                    r: 231,
-                    g: 212,
-                    b: 232,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 217,
-                    g: 240,
-                    b: 211,
-                },
-                RGB {
-                    r: 166,
-                    g: 219,
-                    b: 160,
-                },
-                RGB {
-                    r: 90,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 27,
-                    g: 120,
-                    b: 55,
-                },
-                RGB { r: 0, g: 68, b: 27 },
-            ]),
-            _ => None,
-        },
-        Palette::PiYG => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 233,
-                    g: 163,
-                    b: 201,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 161,
-                    g: 215,
-                    b: 106,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 208,
-                    g: 28,
-                    b: 139,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 77,
-                    g: 172,
-                    b: 38,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 208,
-                    g: 28,
-                    b: 139,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 77,
-                    g: 172,
-                    b: 38,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 233,
-                    g: 163,
-                    b: 201,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 161,
-                    g: 215,
-                    b: 106,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 233,
-                    g: 163,
-                    b: 201,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 161,
-                    g: 215,
-                    b: 106,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 222,
-                    g: 119,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 127,
-                    g: 188,
-                    b: 65,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 222,
-                    g: 119,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 127,
-                    g: 188,
-                    b: 65,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 142,
-                    g: 1,
-                    b: 82,
-                },
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 222,
-                    g: 119,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 127,
-                    g: 188,
-                    b: 65,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-                RGB {
-                    r: 39,
-                    g: 100,
-                    b: 25,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 142,
-                    g: 1,
-                    b: 82,
-                },
-                RGB {
-                    r: 197,
-                    g: 27,
-                    b: 125,
-                },
-                RGB {
-                    r: 222,
-                    g: 119,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 182,
-                    b: 218,
-                },
-                RGB {
-                    r: 253,
-                    g: 224,
-                    b: 239,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 208,
-                },
-                RGB {
-                    r: 184,
-                    g: 225,
-                    b: 134,
-                },
-                RGB {
-                    r: 127,
-                    g: 188,
-                    b: 65,
-                },
-                RGB {
-                    r: 77,
-                    g: 146,
-                    b: 33,
-                },
-                RGB {
-                    r: 39,
-                    g: 100,
-                    b: 25,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::RdBu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 202,
-                    g: 0,
-                    b: 32,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 5,
-                    g: 113,
-                    b: 176,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 202,
-                    g: 0,
-                    b: 32,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 5,
-                    g: 113,
-                    b: 176,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 103,
-                    g: 169,
-                    b: 207,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 67,
-                    g: 147,
-                    b: 195,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 67,
-                    g: 147,
-                    b: 195,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 31,
-                },
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 67,
-                    g: 147,
-                    b: 195,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-                RGB { r: 5, g: 48, b: 97 },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 31,
-                },
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 247,
-                    g: 247,
-                    b: 247,
-                },
-                RGB {
-                    r: 209,
-                    g: 229,
-                    b: 240,
-                },
-                RGB {
-                    r: 146,
-                    g: 197,
-                    b: 222,
-                },
-                RGB {
-                    r: 67,
-                    g: 147,
-                    b: 195,
-                },
-                RGB {
-                    r: 33,
-                    g: 102,
-                    b: 172,
-                },
-                RGB { r: 5, g: 48, b: 97 },
-            ]),
-            _ => None,
-        },
-        Palette::RdGy => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 153,
-                    g: 153,
-                    b: 153,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 202,
-                    g: 0,
-                    b: 32,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 64,
-                    g: 64,
-                    b: 64,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 202,
-                    g: 0,
-                    b: 32,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 64,
-                    g: 64,
-                    b: 64,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 153,
-                    g: 153,
-                    b: 153,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 239,
-                    g: 138,
-                    b: 98,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 153,
-                    g: 153,
-                    b: 153,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 135,
-                    g: 135,
-                    b: 135,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 135,
-                    g: 135,
-                    b: 135,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 31,
-                },
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 135,
-                    g: 135,
-                    b: 135,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-                RGB {
-                    r: 26,
-                    g: 26,
-                    b: 26,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 103,
-                    g: 0,
-                    b: 31,
-                },
-                RGB {
-                    r: 178,
-                    g: 24,
-                    b: 43,
-                },
-                RGB {
-                    r: 214,
-                    g: 96,
-                    b: 77,
-                },
-                RGB {
-                    r: 244,
-                    g: 165,
-                    b: 130,
-                },
-                RGB {
-                    r: 253,
-                    g: 219,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
-                RGB {
-                    r: 224,
-                    g: 224,
-                    b: 224,
-                },
-                RGB {
-                    r: 186,
-                    g: 186,
-                    b: 186,
-                },
-                RGB {
-                    r: 135,
-                    g: 135,
-                    b: 135,
-                },
-                RGB {
-                    r: 77,
-                    g: 77,
-                    b: 77,
-                },
-                RGB {
-                    r: 26,
-                    g: 26,
-                    b: 26,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::RdYlBu => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 145,
-                    g: 191,
-                    b: 219,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 44,
-                    g: 123,
-                    b: 182,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 44,
-                    g: 123,
-                    b: 182,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 145,
-                    g: 191,
-                    b: 219,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 145,
-                    g: 191,
-                    b: 219,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 116,
-                    g: 173,
-                    b: 209,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 116,
-                    g: 173,
-                    b: 209,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 165,
-                    g: 0,
-                    b: 38,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 116,
-                    g: 173,
-                    b: 209,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-                RGB {
-                    r: 49,
-                    g: 54,
-                    b: 149,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 165,
-                    g: 0,
-                    b: 38,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 144,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 224,
-                    g: 243,
-                    b: 248,
-                },
-                RGB {
-                    r: 171,
-                    g: 217,
-                    b: 233,
-                },
-                RGB {
-                    r: 116,
-                    g: 173,
-                    b: 209,
-                },
-                RGB {
-                    r: 69,
-                    g: 117,
-                    b: 180,
-                },
-                RGB {
-                    r: 49,
-                    g: 54,
-                    b: 149,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Spectral => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 153,
-                    g: 213,
-                    b: 148,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 43,
-                    g: 131,
-                    b: 186,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 43,
-                    g: 131,
-                    b: 186,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 153,
-                    g: 213,
-                    b: 148,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 153,
-                    g: 213,
-                    b: 148,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 158,
-                    g: 1,
-                    b: 66,
-                },
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-                RGB {
-                    r: 94,
-                    g: 79,
-                    b: 162,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 158,
-                    g: 1,
-                    b: 66,
-                },
-                RGB {
-                    r: 213,
-                    g: 62,
-                    b: 79,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 152,
-                },
-                RGB {
-                    r: 171,
-                    g: 221,
-                    b: 164,
-                },
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 50,
-                    g: 136,
-                    b: 189,
-                },
-                RGB {
-                    r: 94,
-                    g: 79,
-                    b: 162,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::RdYlGn => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 145,
-                    g: 207,
-                    b: 96,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 26,
-                    g: 150,
-                    b: 65,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 25,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 26,
-                    g: 150,
-                    b: 65,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 145,
-                    g: 207,
-                    b: 96,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 89,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 145,
-                    g: 207,
-                    b: 96,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 102,
-                    g: 189,
-                    b: 99,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 102,
-                    g: 189,
-                    b: 99,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 165,
-                    g: 0,
-                    b: 38,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 102,
-                    g: 189,
-                    b: 99,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-                RGB {
-                    r: 0,
-                    g: 104,
-                    b: 55,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 165,
-                    g: 0,
-                    b: 38,
-                },
-                RGB {
-                    r: 215,
-                    g: 48,
-                    b: 39,
-                },
-                RGB {
-                    r: 244,
-                    g: 109,
-                    b: 67,
-                },
-                RGB {
-                    r: 253,
-                    g: 174,
-                    b: 97,
-                },
-                RGB {
-                    r: 254,
-                    g: 224,
-                    b: 139,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 191,
-                },
-                RGB {
-                    r: 217,
-                    g: 239,
-                    b: 139,
-                },
-                RGB {
-                    r: 166,
-                    g: 217,
-                    b: 106,
-                },
-                RGB {
-                    r: 102,
-                    g: 189,
-                    b: 99,
-                },
-                RGB {
-                    r: 26,
-                    g: 152,
-                    b: 80,
-                },
-                RGB {
-                    r: 0,
-                    g: 104,
-                    b: 55,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Accent => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-                RGB {
-                    r: 56,
-                    g: 108,
-                    b: 176,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-                RGB {
-                    r: 56,
-                    g: 108,
-                    b: 176,
-                },
-                RGB {
-                    r: 240,
-                    g: 2,
-                    b: 127,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-                RGB {
-                    r: 56,
-                    g: 108,
-                    b: 176,
-                },
-                RGB {
-                    r: 240,
-                    g: 2,
-                    b: 127,
-                },
-                RGB {
-                    r: 191,
-                    g: 91,
-                    b: 23,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 127,
-                    g: 201,
-                    b: 127,
-                },
-                RGB {
-                    r: 190,
-                    g: 174,
-                    b: 212,
-                },
-                RGB {
-                    r: 253,
-                    g: 192,
-                    b: 134,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-                RGB {
-                    r: 56,
-                    g: 108,
-                    b: 176,
-                },
-                RGB {
-                    r: 240,
-                    g: 2,
-                    b: 127,
-                },
-                RGB {
-                    r: 191,
-                    g: 91,
-                    b: 23,
-                },
-                RGB {
-                    r: 102,
-                    g: 102,
-                    b: 102,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Dark2 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 102,
-                    g: 166,
-                    b: 30,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 102,
-                    g: 166,
-                    b: 30,
-                },
-                RGB {
-                    r: 230,
-                    g: 171,
-                    b: 2,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 102,
-                    g: 166,
-                    b: 30,
-                },
-                RGB {
-                    r: 230,
-                    g: 171,
-                    b: 2,
-                },
-                RGB {
-                    r: 166,
-                    g: 118,
-                    b: 29,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 27,
-                    g: 158,
-                    b: 119,
-                },
-                RGB {
-                    r: 217,
-                    g: 95,
-                    b: 2,
-                },
-                RGB {
-                    r: 117,
-                    g: 112,
-                    b: 179,
-                },
-                RGB {
-                    r: 231,
-                    g: 41,
-                    b: 138,
-                },
-                RGB {
-                    r: 102,
-                    g: 166,
-                    b: 30,
-                },
-                RGB {
-                    r: 230,
-                    g: 171,
-                    b: 2,
-                },
-                RGB {
-                    r: 166,
-                    g: 118,
-                    b: 29,
-                },
-                RGB {
-                    r: 102,
-                    g: 102,
-                    b: 102,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Paired => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 202,
-                    g: 178,
-                    b: 214,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 202,
-                    g: 178,
-                    b: 214,
-                },
-                RGB {
-                    r: 106,
-                    g: 61,
-                    b: 154,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 202,
-                    g: 178,
-                    b: 214,
-                },
-                RGB {
-                    r: 106,
-                    g: 61,
-                    b: 154,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-            ]),
-            12 => Some(vec![
-                RGB {
-                    r: 166,
-                    g: 206,
-                    b: 227,
-                },
-                RGB {
-                    r: 31,
-                    g: 120,
-                    b: 180,
-                },
-                RGB {
-                    r: 178,
-                    g: 223,
-                    b: 138,
-                },
-                RGB {
-                    r: 51,
-                    g: 160,
-                    b: 44,
-                },
-                RGB {
-                    r: 251,
-                    g: 154,
-                    b: 153,
-                },
-                RGB {
-                    r: 227,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 253,
-                    g: 191,
-                    b: 111,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 202,
-                    g: 178,
-                    b: 214,
-                },
-                RGB {
-                    r: 106,
-                    g: 61,
-                    b: 154,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 153,
-                },
-                RGB {
-                    r: 177,
-                    g: 89,
-                    b: 40,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Pastel1 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 166,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 166,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 166,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 229,
-                    g: 216,
-                    b: 189,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 166,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 229,
-                    g: 216,
-                    b: 189,
-                },
-                RGB {
-                    r: 253,
-                    g: 218,
-                    b: 236,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 251,
-                    g: 180,
-                    b: 174,
-                },
-                RGB {
-                    r: 179,
-                    g: 205,
-                    b: 227,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 222,
-                    g: 203,
-                    b: 228,
-                },
-                RGB {
-                    r: 254,
-                    g: 217,
-                    b: 166,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 204,
-                },
-                RGB {
-                    r: 229,
-                    g: 216,
-                    b: 189,
-                },
-                RGB {
-                    r: 253,
-                    g: 218,
-                    b: 236,
-                },
-                RGB {
-                    r: 242,
-                    g: 242,
-                    b: 242,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Pastel2 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-                RGB {
-                    r: 244,
-                    g: 202,
-                    b: 228,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-                RGB {
-                    r: 244,
-                    g: 202,
-                    b: 228,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 201,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-                RGB {
-                    r: 244,
-                    g: 202,
-                    b: 228,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 201,
-                },
-                RGB {
-                    r: 255,
-                    g: 242,
-                    b: 174,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-                RGB {
-                    r: 244,
-                    g: 202,
-                    b: 228,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 201,
-                },
-                RGB {
-                    r: 255,
-                    g: 242,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 226,
-                    b: 204,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205,
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172,
-                },
-                RGB {
-                    r: 203,
-                    g: 213,
-                    b: 232,
-                },
-                RGB {
-                    r: 244,
-                    g: 202,
-                    b: 228,
-                },
-                RGB {
-                    r: 230,
-                    g: 245,
-                    b: 201,
-                },
-                RGB {
-                    r: 255,
-                    g: 242,
-                    b: 174,
-                },
-                RGB {
-                    r: 241,
-                    g: 226,
-                    b: 204,
-                },
-                RGB {
-                    r: 204,
-                    g: 204,
-                    b: 204,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Set1 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 51,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 51,
-                },
-                RGB {
-                    r: 166,
-                    g: 86,
-                    b: 40,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 51,
-                },
-                RGB {
-                    r: 166,
-                    g: 86,
-                    b: 40,
-                },
-                RGB {
-                    r: 247,
-                    g: 129,
-                    b: 191,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 228,
-                    g: 26,
-                    b: 28,
-                },
-                RGB {
-                    r: 55,
-                    g: 126,
-                    b: 184,
-                },
-                RGB {
-                    r: 77,
-                    g: 175,
-                    b: 74,
-                },
-                RGB {
-                    r: 152,
-                    g: 78,
-                    b: 163,
-                },
-                RGB {
-                    r: 255,
-                    g: 127,
-                    b: 0,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 51,
-                },
-                RGB {
-                    r: 166,
-                    g: 86,
-                    b: 40,
-                },
-                RGB {
-                    r: 247,
-                    g: 129,
-                    b: 191,
-                },
-                RGB {
-                    r: 153,
-                    g: 153,
-                    b: 153,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Set2 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-                RGB {
-                    r: 231,
-                    g: 138,
-                    b: 195,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-                RGB {
-                    r: 231,
-                    g: 138,
-                    b: 195,
-                },
-                RGB {
-                    r: 166,
-                    g: 216,
-                    b: 84,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-                RGB {
-                    r: 231,
-                    g: 138,
-                    b: 195,
-                },
-                RGB {
-                    r: 166,
-                    g: 216,
-                    b: 84,
-                },
-                RGB {
-                    r: 255,
-                    g: 217,
-                    b: 47,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-                RGB {
-                    r: 231,
-                    g: 138,
-                    b: 195,
-                },
-                RGB {
-                    r: 166,
-                    g: 216,
-                    b: 84,
-                },
-                RGB {
-                    r: 255,
-                    g: 217,
-                    b: 47,
-                },
-                RGB {
-                    r: 229,
-                    g: 196,
-                    b: 148,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 102,
-                    g: 194,
-                    b: 165,
-                },
-                RGB {
-                    r: 252,
-                    g: 141,
-                    b: 98,
-                },
-                RGB {
-                    r: 141,
-                    g: 160,
-                    b: 203,
-                },
-                RGB {
-                    r: 231,
-                    g: 138,
-                    b: 195,
-                },
-                RGB {
-                    r: 166,
-                    g: 216,
-                    b: 84,
-                },
-                RGB {
-                    r: 255,
-                    g: 217,
-                    b: 47,
-                },
-                RGB {
-                    r: 229,
-                    g: 196,
-                    b: 148,
-                },
-                RGB {
-                    r: 179,
-                    g: 179,
-                    b: 179,
-                },
-            ]),
-            _ => None,
-        },
-        Palette::Set3 => match nb_value {
-            3 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-            ]),
-            4 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-            ]),
-            5 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-            ]),
-            6 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-            ]),
-            7 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-            ]),
-            8 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-                RGB {
-                    r: 252,
-                    g: 205,
-                    b: 229,
-                },
-            ]),
-            9 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-                RGB {
-                    r: 252,
-                    g: 205,
-                    b: 229,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-            ]),
-            10 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-                RGB {
-                    r: 252,
-                    g: 205,
-                    b: 229,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 188,
-                    g: 128,
-                    b: 189,
-                },
-            ]),
-            11 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-                RGB {
-                    r: 252,
-                    g: 205,
-                    b: 229,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 188,
-                    g: 128,
-                    b: 189,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-            ]),
-            12 => Some(vec![
-                RGB {
-                    r: 141,
-                    g: 211,
-                    b: 199,
-                },
-                RGB {
-                    r: 255,
-                    g: 255,
-                    b: 179,
-                },
-                RGB {
-                    r: 190,
-                    g: 186,
-                    b: 218,
-                },
-                RGB {
-                    r: 251,
-                    g: 128,
-                    b: 114,
-                },
-                RGB {
-                    r: 128,
-                    g: 177,
-                    b: 211,
-                },
-                RGB {
-                    r: 253,
-                    g: 180,
-                    b: 98,
-                },
-                RGB {
-                    r: 179,
-                    g: 222,
-                    b: 105,
-                },
-                RGB {
-                    r: 252,
-                    g: 205,
-                    b: 229,
-                },
-                RGB {
-                    r: 217,
-                    g: 217,
-                    b: 217,
-                },
-                RGB {
-                    r: 188,
-                    g: 128,
-                    b: 189,
-                },
-                RGB {
-                    r: 204,
-                    g: 235,
-                    b: 197,
-                },
-                RGB {
-                    r: 255,
-                    g: 237,
-                    b: 111,
-                },
-            ]),
-            _ => None,
-        },
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{get_color_ramp, Palette};
-    use rgb::RGB;
-
-    #[test]
-    fn test_get_color_ramp() {
-        let ramp = get_color_ramp(Palette::Pastel2, 3);
-        assert_eq!(
-            ramp,
-            Some(vec![
-                RGB {
-                    r: 179,
-                    g: 226,
-                    b: 205
-                },
-                RGB {
-                    r: 253,
-                    g: 205,
-                    b: 172
+                    b: 172
                 },
                 RGB {
                     r: 203,
@@ -9111,4 +619,38 @@ mod tests {
         let palette_pastel2: Palette = "Pastel2".parse().unwrap();
         assert_eq!(palette_pastel2, Palette::Pastel2);
     }
+
+    #[test]
+    fn test_palette_name_round_trip() {
+        for palette in Palette::iter() {
+            assert_eq!(palette.name().parse::<Palette>(), Ok(palette));
+            assert_eq!(palette.to_string().parse::<Palette>(), Ok(palette));
+        }
+    }
+
+    #[test]
+    fn test_display_padding() {
+        assert_eq!(format!("{:<9}|", Palette::Reds), "Reds     |");
+        assert_eq!(format!("{:>7}", Palette::RdBu), "   RdBu");
+        assert_eq!(format!("{:.3}", Palette::Spectral), "Spe");
+    }
+
+    #[test]
+    fn test_all_palettes() {
+        assert_eq!(Palette::iter().count(), 35);
+        for palette in Palette::iter() {
+            assert!(palette.colors(palette.min_classes()).is_some());
+            assert!(palette.colors(palette.max_classes()).is_some());
+        }
+    }
+
+    #[test]
+    fn test_palette_colors() {
+        let colors = Palette::Paired.colors(12).unwrap();
+        assert_eq!(colors.len(), 12);
+        assert_eq!(Some(colors.to_vec()), get_color_ramp(Palette::Paired, 12));
+        assert_eq!(Palette::Paired.colors(2), None);
+        assert_eq!(Palette::Paired.colors(13), None);
+        assert_eq!(Palette::YlGn.colors(0), None);
+    }
 }
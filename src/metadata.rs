@@ -0,0 +1,430 @@
+//! Metadata about the ColorBrewer palettes: scheme type, number of classes
+//! and the properties shipped with the colorbrewer.js dataset.
+
+use crate::Palette;
+
+/// Type of a ColorBrewer color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SchemeType {
+    /// Ordered data progressing from low to high.
+    Sequential,
+    /// Data with a critical midpoint, both extremes being emphasized.
+    Diverging,
+    /// Nominal data, without any magnitude difference between the classes.
+    Qualitative,
+}
+
+/// Rating of a color ramp regarding one of the ColorBrewer properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Suitability {
+    /// The color ramp is suited for this use.
+    Yes,
+    /// The suitability of the color ramp for this use is uncertain.
+    Maybe,
+    /// The color ramp is not suited for this use.
+    No,
+}
+
+impl Suitability {
+    fn from_flag(flag: u8) -> Self {
+        match flag {
+            1 => Suitability::Yes,
+            2 => Suitability::Maybe,
+            _ => Suitability::No,
+        }
+    }
+}
+
+/// Properties of a color ramp (a palette with a given number of colors),
+/// as rated by ColorBrewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Properties {
+    /// Colors can be told apart by people with the most common forms of color blindness.
+    pub colorblind_safe: Suitability,
+    /// Colors can be told apart once printed on a color printer.
+    pub print_friendly: Suitability,
+    /// Colors can be told apart once photocopied in black and white.
+    pub photocopy_safe: Suitability,
+    /// Colors can be told apart on a laptop (LCD) screen.
+    pub lcd_friendly: Suitability,
+}
+
+/// Raw properties of a palette, laid out as in colorbrewer.js: one flag per number
+/// of colors starting at 3 colors, or a single flag applying to every number of colors.
+/// Flags are `1` (yes), `2` (maybe) or `0` (no).
+struct Flags {
+    blind: &'static [u8],
+    print: &'static [u8],
+    copy: &'static [u8],
+    screen: &'static [u8],
+}
+
+fn flag(flags: &[u8], index: usize) -> Suitability {
+    if flags.len() == 1 {
+        Suitability::from_flag(flags[0])
+    } else {
+        Suitability::from_flag(flags[index])
+    }
+}
+
+macro_rules! flags {
+    ($blind:expr, $print:expr, $copy:expr, $screen:expr) => {
+        Flags {
+            blind: &$blind,
+            print: &$print,
+            copy: &$copy,
+            screen: &$screen,
+        }
+    };
+}
+
+impl Palette {
+    /// Type of the color scheme (sequential, diverging or qualitative).
+    ///
+    /// ```rust
+    /// use colorbrewer::{Palette, SchemeType};
+    ///
+    /// assert_eq!(Palette::RdBu.scheme_type(), SchemeType::Diverging);
+    /// ```
+    pub fn scheme_type(&self) -> SchemeType {
+        match self {
+            Palette::YlGn
+            | Palette::YlGnBu
+            | Palette::GnBu
+            | Palette::BuGn
+            | Palette::PuBuGn
+            | Palette::PuBu
+            | Palette::BuPu
+            | Palette::RdPu
+            | Palette::PuRd
+            | Palette::OrRd
+            | Palette::YlOrRd
+            | Palette::YlOrBr
+            | Palette::Purples
+            | Palette::Blues
+            | Palette::Greens
+            | Palette::Oranges
+            | Palette::Reds
+            | Palette::Greys => SchemeType::Sequential,
+            Palette::PuOr
+            | Palette::BrBG
+            | Palette::PRGn
+            | Palette::PiYG
+            | Palette::RdBu
+            | Palette::RdGy
+            | Palette::RdYlBu
+            | Palette::Spectral
+            | Palette::RdYlGn => SchemeType::Diverging,
+            Palette::Accent
+            | Palette::Dark2
+            | Palette::Paired
+            | Palette::Pastel1
+            | Palette::Pastel2
+            | Palette::Set1
+            | Palette::Set2
+            | Palette::Set3 => SchemeType::Qualitative,
+        }
+    }
+
+    /// Minimum number of colors that can be requested for this palette.
+    pub fn min_classes(&self) -> u32 {
+        3
+    }
+
+    /// Maximum number of colors that can be requested for this palette.
+    ///
+    /// ```rust
+    /// use colorbrewer::Palette;
+    ///
+    /// assert_eq!(Palette::Accent.max_classes(), 8);
+    /// assert_eq!(Palette::Paired.max_classes(), 12);
+    /// ```
+    pub fn max_classes(&self) -> u32 {
+        self.min_classes() + self.ramps().len() as u32 - 1
+    }
+
+    /// ColorBrewer properties of the color ramp with `nb_value` colors.
+    /// Return `None` if there is no color ramp defined for this value of `nb_value`.
+    ///
+    /// ```rust
+    /// use colorbrewer::{Palette, Suitability};
+    ///
+    /// let properties = Palette::Spectral.properties(3).unwrap();
+    /// assert_eq!(properties.colorblind_safe, Suitability::Maybe);
+    /// assert_eq!(properties.photocopy_safe, Suitability::No);
+    /// ```
+    pub fn properties(&self, nb_value: u32) -> Option<Properties> {
+        if nb_value < self.min_classes() || nb_value > self.max_classes() {
+            return None;
+        }
+        let index = (nb_value - self.min_classes()) as usize;
+        let flags = self.flags();
+        Some(Properties {
+            colorblind_safe: flag(flags.blind, index),
+            print_friendly: flag(flags.print, index),
+            photocopy_safe: flag(flags.copy, index),
+            lcd_friendly: flag(flags.screen, index),
+        })
+    }
+
+    fn flags(&self) -> Flags {
+        match self {
+            Palette::YlGn => flags!(
+                [1],
+                [1, 1, 1, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0]
+            ),
+            Palette::YlGnBu => flags!(
+                [1],
+                [1, 1, 1, 2, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0]
+            ),
+            Palette::GnBu => flags!(
+                [1],
+                [1, 1, 2, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0]
+            ),
+            Palette::BuGn => flags!(
+                [1],
+                [1, 1, 2, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0]
+            ),
+            Palette::PuBuGn => flags!(
+                [1],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0]
+            ),
+            Palette::PuBu => flags!(
+                [1],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0]
+            ),
+            Palette::BuPu => flags!(
+                [1],
+                [1, 1, 2, 2, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0]
+            ),
+            Palette::RdPu => flags!(
+                [1],
+                [1, 1, 1, 2, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0]
+            ),
+            Palette::PuRd => flags!(
+                [1],
+                [1, 1, 1, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0]
+            ),
+            Palette::OrRd => flags!(
+                [1],
+                [1, 1, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0]
+            ),
+            Palette::YlOrRd => flags!(
+                [1],
+                [1, 1, 2, 2, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0]
+            ),
+            Palette::YlOrBr => flags!(
+                [1],
+                [1, 1, 2, 2, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0]
+            ),
+            Palette::Purples => flags!(
+                [1],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::Blues => flags!(
+                [1],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0]
+            ),
+            Palette::Greens => flags!(
+                [1],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0]
+            ),
+            Palette::Oranges => flags!(
+                [1],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::Reds => flags!(
+                [1],
+                [1, 2, 2, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0]
+            ),
+            Palette::Greys => flags!(
+                [1],
+                [1, 1, 0, 0, 0, 0, 0],
+                [1, 0, 0, 0, 0, 0, 0],
+                [1, 2, 0, 0, 0, 0, 0]
+            ),
+            Palette::PuOr => flags!(
+                [1],
+                [1, 1, 0, 0, 0, 0, 0, 0, 0],
+                [1, 1, 0, 0, 0, 0, 0, 0, 0],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0]
+            ),
+            Palette::BrBG => flags!(
+                [1],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0]
+            ),
+            Palette::PRGn => flags!(
+                [1],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::PiYG => flags!(
+                [1],
+                [1, 1, 2, 0, 0, 0, 0, 0, 0],
+                [1, 1, 0, 0, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::RdBu => flags!(
+                [1],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [0],
+                [1, 1, 1, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::RdGy => flags!(
+                [2],
+                [1, 1, 1, 2, 0, 0, 0, 0, 0],
+                [0],
+                [1, 1, 0, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::RdYlBu => flags!(
+                [1],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0],
+                [0],
+                [1, 1, 1, 1, 0, 0, 0, 0, 0]
+            ),
+            Palette::Spectral => flags!(
+                [2, 2, 2, 0, 0, 0, 0, 0, 0],
+                [1, 1, 1, 0, 0, 0, 0, 0, 0],
+                [0],
+                [1, 1, 2, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::RdYlGn => flags!(
+                [2, 2, 2, 0, 0, 0, 0, 0, 0],
+                [1, 1, 1, 2, 0, 0, 0, 0, 0],
+                [0],
+                [1, 1, 1, 0, 0, 0, 0, 0, 0]
+            ),
+            Palette::Accent => flags!(
+                [2, 0, 0, 0, 0, 0],
+                [1, 1, 2, 0, 0, 0],
+                [0],
+                [1, 1, 1, 0, 0, 0]
+            ),
+            Palette::Dark2 => flags!([1, 2, 2, 0, 0, 0], [1], [0], [1, 1, 1, 2, 0, 0]),
+            Palette::Paired => flags!(
+                [1, 1, 2, 2, 2, 2, 0, 0, 0, 0],
+                [1, 1, 1, 1, 1, 2, 2, 2, 2, 2],
+                [0],
+                [1, 1, 1, 1, 1, 1, 1, 1, 2, 2]
+            ),
+            Palette::Pastel1 => flags!(
+                [2, 0, 0, 0, 0, 0, 0],
+                [2, 2, 2, 2, 0, 0, 0],
+                [0],
+                [2, 2, 2, 2, 0, 0, 0]
+            ),
+            Palette::Pastel2 => flags!(
+                [2, 0, 0, 0, 0, 0],
+                [2, 0, 0, 0, 0, 0],
+                [0],
+                [2, 2, 2, 0, 0, 0]
+            ),
+            Palette::Set1 => flags!([2], [1], [0], [1]),
+            Palette::Set2 => flags!(
+                [1, 2, 2, 2, 0, 0],
+                [1, 1, 1, 2, 2, 2],
+                [0],
+                [1, 1, 2, 2, 2, 2]
+            ),
+            Palette::Set3 => flags!(
+                [2, 2, 0, 0, 0, 0, 0, 0, 0, 0],
+                [1, 1, 1, 1, 1, 1, 2, 0, 0, 0],
+                [1, 2, 2, 2, 2, 2, 2, 0, 0, 0],
+                [1, 1, 1, 2, 2, 2, 0, 0, 0, 0]
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Palette, SchemeType, Suitability};
+
+    #[test]
+    fn test_classes_range() {
+        assert_eq!(Palette::Blues.min_classes(), 3);
+        assert_eq!(Palette::Blues.max_classes(), 9);
+        assert_eq!(Palette::RdBu.max_classes(), 11);
+        assert_eq!(Palette::Set3.max_classes(), 12);
+        assert_eq!(Palette::Pastel2.max_classes(), 8);
+    }
+
+    #[test]
+    fn test_scheme_type() {
+        assert_eq!(Palette::YlOrBr.scheme_type(), SchemeType::Sequential);
+        assert_eq!(Palette::Spectral.scheme_type(), SchemeType::Diverging);
+        assert_eq!(Palette::Set1.scheme_type(), SchemeType::Qualitative);
+    }
+
+    #[test]
+    fn test_flags_cover_all_ramps() {
+        for palette in Palette::iter() {
+            let nb_ramps = (palette.max_classes() - palette.min_classes() + 1) as usize;
+            let flags = palette.flags();
+            for values in &[flags.blind, flags.print, flags.copy, flags.screen] {
+                assert!(
+                    values.len() == 1 || values.len() == nb_ramps,
+                    "{:?}",
+                    palette
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_properties() {
+        let properties = Palette::YlGn.properties(4).unwrap();
+        assert_eq!(properties.colorblind_safe, Suitability::Yes);
+        assert_eq!(properties.print_friendly, Suitability::Yes);
+        assert_eq!(properties.photocopy_safe, Suitability::Maybe);
+        assert_eq!(properties.lcd_friendly, Suitability::Yes);
+        assert_eq!(Palette::YlGn.properties(2), None);
+        assert_eq!(Palette::YlGn.properties(10), None);
+        assert_eq!(
+            Palette::Paired.properties(12).unwrap().colorblind_safe,
+            Suitability::No
+        );
+    }
+}
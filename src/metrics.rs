@@ -0,0 +1,235 @@
+//! Perceptual distances between colors, and metrics evaluating how well the colors
+//! of a ramp can be told apart.
+//!
+//! ```rust
+//! use colorbrewer::metrics::{delta_e, DeltaE};
+//! use colorbrewer::Palette;
+//!
+//! let colors = Palette::Blues.colors(3).unwrap();
+//! let distance = delta_e(colors[0], colors[1], DeltaE::Ciede2000);
+//! assert!(distance > 10.);
+//!
+//! let report = Palette::Blues.report(7, DeltaE::Ciede2000).unwrap();
+//! assert_eq!(report.lightness_monotonic, Some(true));
+//! ```
+
+use crate::colorspace::{rgb_to_lab, rgb_to_oklab};
+use crate::{Palette, SchemeType};
+use rgb::RGB;
+use std::f64::consts::PI;
+
+/// Color difference formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DeltaE {
+    /// Euclidean distance in CIELAB (a difference of about 2.3 is just noticeable).
+    Cie76,
+    /// CIEDE2000, correcting the perceptual non-uniformities of CIELAB
+    /// (a difference of about 1 is just noticeable).
+    #[default]
+    Ciede2000,
+    /// Euclidean distance in OKLab, scaled by 100 to be comparable with the CIELAB
+    /// based differences.
+    Oklab,
+}
+
+/// Perceptual difference between two colors.
+pub fn delta_e(a: RGB<u8>, b: RGB<u8>, metric: DeltaE) -> f64 {
+    match metric {
+        DeltaE::Cie76 => euclidean(rgb_to_lab(a), rgb_to_lab(b)),
+        DeltaE::Ciede2000 => ciede2000(rgb_to_lab(a), rgb_to_lab(b)),
+        DeltaE::Oklab => 100. * euclidean(rgb_to_oklab(a), rgb_to_oklab(b)),
+    }
+}
+
+fn euclidean(a: [f64; 3], b: [f64; 3]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// CIEDE2000 difference between two CIELAB colors
+/// (following Sharma, Wu and Dalal, 2005).
+fn ciede2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let [l1, a1, b1] = lab1;
+    let [l2, a2, b2] = lab2;
+    let c_mean = ((a1.hypot(b1)) + (a2.hypot(b2))) / 2.;
+    let g = 0.5 * (1. - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+    let (a1, a2) = (a1 * (1. + g), a2 * (1. + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |b: f64, a: f64| {
+        if a == 0. && b == 0. {
+            0.
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.)
+        }
+    };
+    let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0. {
+        0.
+    } else if (h2 - h1).abs() <= 180. {
+        h2 - h1
+    } else if h2 - h1 > 180. {
+        h2 - h1 - 360.
+    } else {
+        h2 - h1 + 360.
+    };
+    let delta_big_h = 2. * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.).sin();
+
+    let l_mean = (l1 + l2) / 2.;
+    let c_mean = (c1 + c2) / 2.;
+    let h_mean = if c1 * c2 == 0. {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180. {
+        (h1 + h2) / 2.
+    } else if h1 + h2 < 360. {
+        (h1 + h2 + 360.) / 2.
+    } else {
+        (h1 + h2 - 360.) / 2.
+    };
+    let t = 1. - 0.17 * (h_mean - 30.).to_radians().cos()
+        + 0.24 * (2. * h_mean).to_radians().cos()
+        + 0.32 * (3. * h_mean + 6.).to_radians().cos()
+        - 0.20 * (4. * h_mean - 63.).to_radians().cos();
+    let delta_theta = 30. * (-((h_mean - 275.) / 25.).powi(2)).exp();
+    let r_c = 2. * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l = 1. + (0.015 * (l_mean - 50.).powi(2)) / (20. + (l_mean - 50.).powi(2)).sqrt();
+    let s_c = 1. + 0.045 * c_mean;
+    let s_h = 1. + 0.015 * c_mean * t;
+    let r_t = -(2. * delta_theta * PI / 180.).sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_big_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// Metrics evaluating how well the colors of a ramp can be told apart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RampReport {
+    /// Smallest difference between two consecutive colors.
+    pub min_adjacent: f64,
+    /// Smallest difference between any two colors.
+    pub min_pairwise: f64,
+    /// Whether the CIELAB lightness of the colors strictly increases or strictly
+    /// decreases along the ramp (only computed for sequential palettes).
+    pub lightness_monotonic: Option<bool>,
+    /// Greatest CIELAB lightness difference between two colors at the same distance
+    /// from the center of the ramp, `0` meaning perfectly symmetric (only computed
+    /// for diverging palettes).
+    pub lightness_asymmetry: Option<f64>,
+}
+
+/// Compute the distances between the colors of a ramp.
+/// The scheme dependent metrics are computed according to `scheme_type`.
+pub fn report(colors: &[RGB<u8>], metric: DeltaE, scheme_type: SchemeType) -> RampReport {
+    let mut min_adjacent = f64::INFINITY;
+    let mut min_pairwise = f64::INFINITY;
+    for (i, &a) in colors.iter().enumerate() {
+        for (j, &b) in colors.iter().enumerate().skip(i + 1) {
+            let distance = delta_e(a, b, metric);
+            min_pairwise = min_pairwise.min(distance);
+            if j == i + 1 {
+                min_adjacent = min_adjacent.min(distance);
+            }
+        }
+    }
+    let lightness: Vec<f64> = colors.iter().map(|&c| rgb_to_lab(c)[0]).collect();
+    let lightness_monotonic = match scheme_type {
+        SchemeType::Sequential => Some(
+            lightness.windows(2).all(|w| w[0] < w[1]) || lightness.windows(2).all(|w| w[0] > w[1]),
+        ),
+        _ => None,
+    };
+    let lightness_asymmetry = match scheme_type {
+        SchemeType::Diverging => Some(
+            lightness
+                .iter()
+                .zip(lightness.iter().rev())
+                .map(|(a, b)| (a - b).abs())
+                .fold(0., f64::max),
+        ),
+        _ => None,
+    };
+    RampReport {
+        min_adjacent,
+        min_pairwise,
+        lightness_monotonic,
+        lightness_asymmetry,
+    }
+}
+
+impl Palette {
+    /// Compute the distances between the colors of the ramp of this palette with
+    /// `nb_value` colors. Return `None` if there is no color ramp defined for this
+    /// value of `nb_value`.
+    pub fn report(&self, nb_value: u32, metric: DeltaE) -> Option<RampReport> {
+        self.colors(nb_value)
+            .map(|colors| report(colors, metric, self.scheme_type()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ciede2000_reference_values() {
+        // Test data from Sharma, Wu and Dalal (2005).
+        let pairs = [
+            ([50., 2.6772, -79.7751], [50., 0., -82.7485], 2.0425),
+            ([50., -1.3802, -84.2814], [50., 0., -82.7485], 1.0),
+            ([50., 2.5, 0.], [73., 25., -18.], 27.1492),
+            ([50., 2.5, 0.], [50., 3.2592, 0.3350], 1.0),
+            (
+                [60.2574, -34.0099, 36.2677],
+                [60.4626, -34.1751, 39.4387],
+                1.2644,
+            ),
+            (
+                [22.7233, 20.0904, -46.6940],
+                [23.0331, 14.9730, -42.5619],
+                2.0373,
+            ),
+            (
+                [2.0776, 0.0795, -1.1350],
+                [0.9033, -0.0636, -0.5514],
+                0.9082,
+            ),
+        ];
+        for &(a, b, expected) in &pairs {
+            assert!((ciede2000(a, b) - expected).abs() < 1e-4, "{:?} {:?}", a, b);
+            assert!((ciede2000(b, a) - expected).abs() < 1e-4, "{:?} {:?}", b, a);
+        }
+    }
+
+    #[test]
+    fn test_delta_e() {
+        let (black, white) = (RGB::new(0, 0, 0), RGB::new(255, 255, 255));
+        assert!((delta_e(black, white, DeltaE::Cie76) - 100.).abs() < 1e-3);
+        assert!((delta_e(black, white, DeltaE::Ciede2000) - 100.).abs() < 1e-3);
+        assert!((delta_e(black, white, DeltaE::Oklab) - 100.).abs() < 1e-3);
+        assert_eq!(delta_e(white, white, DeltaE::Ciede2000), 0.);
+    }
+
+    #[test]
+    fn test_report() {
+        for palette in Palette::iter() {
+            let report = palette
+                .report(palette.max_classes(), DeltaE::Ciede2000)
+                .unwrap();
+            assert!(report.min_pairwise <= report.min_adjacent);
+            assert!(report.min_pairwise > 0.);
+            match palette.scheme_type() {
+                SchemeType::Sequential => assert_eq!(report.lightness_monotonic, Some(true)),
+                SchemeType::Diverging => assert!(report.lightness_asymmetry.is_some()),
+                SchemeType::Qualitative => {
+                    assert_eq!(report.lightness_monotonic, None);
+                    assert_eq!(report.lightness_asymmetry, None);
+                }
+            }
+        }
+        let rdbu = Palette::RdBu.report(11, DeltaE::Oklab).unwrap();
+        let puor = Palette::PuOr.report(11, DeltaE::Oklab).unwrap();
+        assert!(rdbu.lightness_asymmetry < puor.lightness_asymmetry);
+        assert_eq!(Palette::Set1.report(10, DeltaE::Cie76), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Lenient parsing of palette names, as typed by users or used by other tools.
+
+use crate::{Error, Palette};
+
+/// Palette parsed by [`Palette::parse_lenient`], with the reversal flag
+/// given by a `_r` suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedPalette {
+    /// The palette matching the name.
+    pub palette: Palette,
+    /// Whether the name asked for the colors in reverse order.
+    pub reversed: bool,
+}
+
+/// Alternative spellings, matched after normalization
+/// (lowercase, without separators and with "gray" spelled "grey").
+const ALIASES: &[(&str, Palette)] = &[("grey", Palette::Greys), ("rdgrey", Palette::RdGy)];
+
+impl Palette {
+    /// Parse a palette name leniently: the case and the `-`, `_` and space separators
+    /// are ignored, "gray" is accepted for "grey" and a `_r` suffix (as used by
+    /// matplotlib) asks for the colors in reverse order.
+    ///
+    /// The `FromStr` implementation of `Palette` only accepts the exact ColorBrewer names.
+    ///
+    /// ```rust
+    /// use colorbrewer::Palette;
+    ///
+    /// let parsed = Palette::parse_lenient("rdylbu_r").unwrap();
+    /// assert_eq!(parsed.palette, Palette::RdYlBu);
+    /// assert!(parsed.reversed);
+    /// assert_eq!(Palette::parse_lenient("Grays").unwrap().palette, Palette::Greys);
+    /// ```
+    pub fn parse_lenient(name: &str) -> Result<ParsedPalette, Error> {
+        let lowercase = name.trim().to_lowercase();
+        let (base, reversed) = match lowercase.strip_suffix("_r") {
+            Some(base) => (base, true),
+            None => (lowercase.as_str(), false),
+        };
+        let normalized = base
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .replace("gray", "grey");
+        Palette::iter()
+            .find(|palette| palette.name().to_lowercase() == normalized)
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == normalized)
+                    .map(|&(_, palette)| palette)
+            })
+            .map(|palette| ParsedPalette { palette, reversed })
+            .ok_or_else(|| Error::unknown_palette(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Palette, ParsedPalette};
+
+    #[test]
+    fn test_parse_lenient() {
+        let parsed = |name| Palette::parse_lenient(name).unwrap();
+        assert_eq!(
+            parsed("rdylbu"),
+            ParsedPalette {
+                palette: Palette::RdYlBu,
+                reversed: false,
+            }
+        );
+        assert_eq!(
+            parsed("RdYlBu_r"),
+            ParsedPalette {
+                palette: Palette::RdYlBu,
+                reversed: true,
+            }
+        );
+        assert_eq!(parsed("Greys").palette, Palette::Greys);
+        assert_eq!(parsed("grays").palette, Palette::Greys);
+        assert_eq!(parsed("Gray_R").palette, Palette::Greys);
+        assert_eq!(parsed("rd-gray").palette, Palette::RdGy);
+        assert_eq!(parsed(" set 3 ").palette, Palette::Set3);
+        assert_eq!(parsed("PASTEL1_r").palette, Palette::Pastel1);
+    }
+
+    #[test]
+    fn test_parse_lenient_unknown() {
+        assert!(matches!(
+            Palette::parse_lenient("viridis_r"),
+            Err(Error::UnknownPalette {
+                suggestion: None,
+                ..
+            })
+        ));
+        assert!("rdylbu".parse::<Palette>().is_err());
+    }
+}
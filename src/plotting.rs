@@ -0,0 +1,163 @@
+//! Use of the palettes with the [`plotters`] crate, enabled with the `plotters` feature.
+//!
+//! A [`Colormap`] implements the [`ColorMap`] trait of `plotters`, so that a sequential
+//! or diverging palette can color a heatmap or a surface, and [`SeriesColors`] cycles
+//! through the colors of a (qualitative) palette to color the series of a chart.
+//!
+//! ```rust
+//! use colorbrewer::plotting::SeriesColors;
+//! use colorbrewer::{Interpolation, Palette};
+//! use plotters::style::colors::colormaps::ColorMap;
+//! use plotters::style::RGBColor;
+//!
+//! let series = SeriesColors::new(Palette::Set1, 5).unwrap();
+//! assert_eq!(series.pick(0), RGBColor(228, 26, 28));
+//! assert_eq!(series.pick(5), series.pick(0));
+//!
+//! let colormap = Palette::YlOrRd.colormap(Interpolation::Oklab);
+//! let color: RGBColor = colormap.get_color_normalized(12.5f64, 10., 20.);
+//! ```
+
+use crate::{Colormap, Error, Palette};
+use plotters::style::colors::colormaps::ColorMap;
+use plotters::style::RGBColor;
+use rgb::RGB;
+
+/// Convert a color to a `plotters` color.
+pub fn to_rgb_color(color: RGB<u8>) -> RGBColor {
+    RGBColor(color.r, color.g, color.b)
+}
+
+/// Convert a `plotters` color to a color.
+pub fn from_rgb_color(color: RGBColor) -> RGB<u8> {
+    RGB {
+        r: color.0,
+        g: color.1,
+        b: color.2,
+    }
+}
+
+/// Convert colors, such as a [`Ramp`](crate::Ramp), to `plotters` colors.
+pub fn to_rgb_colors(colors: &[RGB<u8>]) -> Vec<RGBColor> {
+    colors.iter().map(|&color| to_rgb_color(color)).collect()
+}
+
+impl Colormap {
+    fn eval_normalized(&self, value: f64, min: f64, max: f64) -> RGBColor {
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.5
+        };
+        to_rgb_color(self.eval(t))
+    }
+}
+
+impl ColorMap<RGBColor, f32> for Colormap {
+    fn get_color_normalized(&self, h: f32, min: f32, max: f32) -> RGBColor {
+        self.eval_normalized(f64::from(h), f64::from(min), f64::from(max))
+    }
+}
+
+impl ColorMap<RGBColor, f64> for Colormap {
+    fn get_color_normalized(&self, h: f64, min: f64, max: f64) -> RGBColor {
+        self.eval_normalized(h, min, max)
+    }
+}
+
+/// Colors of the series of a chart, cycling through the colors of a ramp.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeriesColors {
+    colors: Vec<RGBColor>,
+}
+
+impl SeriesColors {
+    /// Cycle through the ramp of `palette` with `nb_value` colors.
+    ///
+    /// Return an error if the palette doesn't define this number of colors.
+    pub fn new(palette: Palette, nb_value: u32) -> Result<Self, Error> {
+        Ok(SeriesColors::from_colors(palette.try_colors(nb_value)?))
+    }
+
+    /// Cycle through arbitrary colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty.
+    pub fn from_colors(colors: &[RGB<u8>]) -> Self {
+        assert!(
+            !colors.is_empty(),
+            "a series color cycle needs at least one color"
+        );
+        SeriesColors {
+            colors: to_rgb_colors(colors),
+        }
+    }
+
+    /// Number of distinct colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Always `false`, a color cycle has at least one color.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Color of the series `index`, starting again from the first color
+    /// after the last one.
+    pub fn pick(&self, index: usize) -> RGBColor {
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Endless iterator over the colors.
+    pub fn iter(&self) -> impl Iterator<Item = RGBColor> + '_ {
+        self.colors.iter().copied().cycle()
+    }
+}
+
+impl From<Palette> for SeriesColors {
+    /// Cycle through the largest ramp of the palette.
+    fn from(palette: Palette) -> Self {
+        SeriesColors::new(palette, palette.max_classes())
+            .expect("the largest ramp of a palette is always defined")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Interpolation;
+
+    #[test]
+    fn test_conversions() {
+        let color = RGB { r: 1, g: 2, b: 3 };
+        assert_eq!(to_rgb_color(color), RGBColor(1, 2, 3));
+        assert_eq!(from_rgb_color(to_rgb_color(color)), color);
+        let ramp = Palette::Blues.ramp(3).unwrap();
+        assert_eq!(to_rgb_colors(&ramp)[2], RGBColor(49, 130, 189));
+    }
+
+    #[test]
+    fn test_colormap() {
+        let colormap = Palette::RdBu.colormap(Interpolation::Lab);
+        let first = to_rgb_color(colormap.eval(0.));
+        let last = to_rgb_color(colormap.eval(1.));
+        assert_eq!(ColorMap::<RGBColor, f32>::get_color(&colormap, 0.), first);
+        assert_eq!(colormap.get_color_normalized(-5f64, -5., 5.), first);
+        assert_eq!(colormap.get_color_normalized(50f64, -5., 5.), last);
+        assert_eq!(
+            colormap.get_color_normalized(0f64, 1., 1.),
+            to_rgb_color(colormap.eval(0.5))
+        );
+    }
+
+    #[test]
+    fn test_series_colors() {
+        let series = SeriesColors::from(Palette::Dark2);
+        assert_eq!(series.len(), 8);
+        assert_eq!(series.pick(9), series.pick(1));
+        assert_eq!(series.iter().nth(8), Some(series.pick(0)));
+        assert!(SeriesColors::new(Palette::Dark2, 9).is_err());
+    }
+}
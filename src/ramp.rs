@@ -0,0 +1,261 @@
+//! Owned color ramps.
+
+use crate::{format, Colormap, Error, Interpolation, Palette};
+use rgb::RGB;
+use std::ops::Deref;
+
+/// An owned color ramp: an ordered list of colors.
+///
+/// It dereferences to a slice of colors and can be converted from and into a `Vec`.
+///
+/// ```rust
+/// use colorbrewer::{Palette, Ramp};
+///
+/// let ramp = Palette::Blues.ramp(5).unwrap();
+/// assert_eq!(ramp.len(), 5);
+/// let colors: Vec<rgb::RGB<u8>> = ramp.into();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Ramp {
+    colors: Vec<RGB<u8>>,
+}
+
+impl Ramp {
+    /// Create a ramp from arbitrary colors.
+    pub fn new(colors: Vec<RGB<u8>>) -> Self {
+        Ramp { colors }
+    }
+
+    /// Create a ramp from hexadecimal colors (`#rrggbb` or `#rgb`, the `#` being optional),
+    /// for example to mix custom colors with ColorBrewer ones.
+    /// Return an [`Error::InvalidColor`] for the first invalid color.
+    pub fn from_hex<S: AsRef<str>>(colors: &[S]) -> Result<Self, Error> {
+        colors
+            .iter()
+            .map(|s| format::parse_hex(s.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Ramp::new)
+    }
+
+    /// The colors of the ramp.
+    pub fn colors(&self) -> &[RGB<u8>] {
+        &self.colors
+    }
+
+    /// Consume the ramp, returning its colors.
+    pub fn into_colors(self) -> Vec<RGB<u8>> {
+        self.colors
+    }
+
+    /// The colors of the ramp as `#rrggbb` hexadecimal strings.
+    pub fn to_hex(&self) -> Vec<String> {
+        self.colors.iter().map(|&c| format::to_hex(c)).collect()
+    }
+
+    /// The colors of the ramp as CSS `rgb(r, g, b)` strings.
+    pub fn to_css(&self) -> Vec<String> {
+        self.colors.iter().map(|&c| format::to_css(c)).collect()
+    }
+
+    /// The colors of the ramp packed as `0xRRGGBB` integers.
+    pub fn to_u32(&self) -> Vec<u32> {
+        self.colors.iter().map(|&c| format::to_u32(c)).collect()
+    }
+
+    /// The colors of the ramp with their components normalized to `[0, 1]`.
+    pub fn to_f32(&self) -> Vec<[f32; 3]> {
+        self.colors.iter().map(|&c| format::to_f32(c)).collect()
+    }
+
+    /// Reverse the order of the colors of the ramp.
+    pub fn reversed(mut self) -> Self {
+        self.colors.reverse();
+        self
+    }
+}
+
+impl Deref for Ramp {
+    type Target = [RGB<u8>];
+
+    fn deref(&self) -> &[RGB<u8>] {
+        &self.colors
+    }
+}
+
+impl From<Vec<RGB<u8>>> for Ramp {
+    fn from(colors: Vec<RGB<u8>>) -> Self {
+        Ramp { colors }
+    }
+}
+
+impl From<&[RGB<u8>]> for Ramp {
+    fn from(colors: &[RGB<u8>]) -> Self {
+        Ramp {
+            colors: colors.to_vec(),
+        }
+    }
+}
+
+impl From<Ramp> for Vec<RGB<u8>> {
+    fn from(ramp: Ramp) -> Self {
+        ramp.colors
+    }
+}
+
+impl<'a> IntoIterator for &'a Ramp {
+    type Item = &'a RGB<u8>;
+    type IntoIter = std::slice::Iter<'a, RGB<u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
+
+impl IntoIterator for Ramp {
+    type Item = RGB<u8>;
+    type IntoIter = std::vec::IntoIter<RGB<u8>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.into_iter()
+    }
+}
+
+/// Start building the color ramp of `palette` with `nb_value` colors,
+/// optionally reversed or taken from a sub-interval of the palette.
+///
+/// ```rust
+/// use colorbrewer::{ramp, Palette};
+///
+/// // The ColorBrewer ramp, blue to red instead of red to blue:
+/// let reversed = ramp(Palette::RdBu, 7).reversed().build().unwrap();
+/// assert_eq!(reversed[0], Palette::RdBu.colors(7).unwrap()[6]);
+///
+/// // 4 colors from the dark half of the palette:
+/// let dark_blues = ramp(Palette::Blues, 4).interval(0.5, 1.).build().unwrap();
+/// assert_eq!(dark_blues[3], Palette::Blues.colors(9).unwrap()[8]);
+/// ```
+pub fn ramp(palette: Palette, nb_value: u32) -> RampBuilder {
+    RampBuilder {
+        palette,
+        nb_value,
+        reversed: false,
+        interval: None,
+        interpolation: Interpolation::default(),
+    }
+}
+
+/// Builder for a color ramp, created with the [`ramp`] function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RampBuilder {
+    palette: Palette,
+    nb_value: u32,
+    reversed: bool,
+    interval: Option<(f64, f64)>,
+    interpolation: Interpolation,
+}
+
+impl RampBuilder {
+    /// Reverse the order of the colors.
+    pub fn reversed(mut self) -> Self {
+        self.reversed = !self.reversed;
+        self
+    }
+
+    /// Take the colors evenly spaced between `start` and `end`, `0` being the first color
+    /// and `1` the last color of the largest ramp of the palette, instead of using the
+    /// ColorBrewer ramp. The colors are interpolated (see [`RampBuilder::interpolation`])
+    /// and any number of colors can be requested.
+    pub fn interval(mut self, start: f64, end: f64) -> Self {
+        self.interval = Some((start, end));
+        self
+    }
+
+    /// Color space used to interpolate the colors when an interval is set
+    /// (OKLab by default).
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Build the color ramp. Return an [`Error::InvalidClassCount`] if no interval is set
+    /// and there is no ColorBrewer ramp defined for the requested number of colors.
+    pub fn build(&self) -> Result<Ramp, Error> {
+        let ramp = match self.interval {
+            None => Ramp::from(self.palette.try_colors(self.nb_value)?),
+            Some((start, end)) => {
+                let colormap = Colormap::new(self.palette, self.interpolation);
+                let n = self.nb_value as usize;
+                let colors = (0..n)
+                    .map(|i| {
+                        let t = if n > 1 {
+                            i as f64 / (n - 1) as f64
+                        } else {
+                            0.5
+                        };
+                        colormap.eval(start + (end - start) * t)
+                    })
+                    .collect();
+                Ramp::new(colors)
+            }
+        };
+        Ok(if self.reversed { ramp.reversed() } else { ramp })
+    }
+}
+
+impl Palette {
+    /// Get the color ramp of this palette with `nb_value` colors, as an owned [`Ramp`].
+    /// Return `None` if there is no color ramp defined for this value of `nb_value`.
+    pub fn ramp(&self, nb_value: u32) -> Option<Ramp> {
+        self.colors(nb_value).map(Ramp::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ramp, Error, Interpolation, Palette, Ramp};
+
+    #[test]
+    fn test_reversed() {
+        let colors = Palette::RdBu.colors(7).unwrap();
+        let reversed = Palette::RdBu.ramp(7).map(Ramp::reversed).unwrap();
+        assert!(reversed.iter().eq(colors.iter().rev()));
+        assert_eq!(ramp(Palette::RdBu, 7).reversed().build(), Ok(reversed));
+        assert_eq!(
+            ramp(Palette::RdBu, 7).reversed().reversed().build(),
+            Ok(Ramp::from(colors))
+        );
+    }
+
+    #[test]
+    fn test_interval() {
+        let blues = Palette::Blues.colors(9).unwrap();
+        let ramp_blues = ramp(Palette::Blues, 5)
+            .interval(0.5, 1.)
+            .interpolation(Interpolation::Srgb)
+            .build()
+            .unwrap();
+        assert_eq!(ramp_blues.colors(), &blues[4..]);
+        let reversed = ramp(Palette::Blues, 5)
+            .interval(1., 0.5)
+            .interpolation(Interpolation::Srgb)
+            .build()
+            .unwrap();
+        assert_eq!(reversed, ramp_blues.clone().reversed());
+        assert_eq!(
+            ramp(Palette::Blues, 15)
+                .interval(0., 1.)
+                .build()
+                .unwrap()
+                .len(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_invalid_class_count() {
+        assert!(matches!(
+            ramp(Palette::Blues, 15).build(),
+            Err(Error::InvalidClassCount { max: 9, .. })
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+//! Bitmap swatches of the color ramps, as [`image::RgbImage`], enabled with the
+//! `image` feature.
+//!
+//! The images can be saved as PNG files with [`image::RgbImage::save`].
+//!
+//! ```rust
+//! use colorbrewer::raster::{contact_sheet, palette_strip};
+//! use colorbrewer::Palette;
+//!
+//! let strip = palette_strip(Palette::Oranges, 5, 20).unwrap();
+//! assert_eq!(strip.dimensions(), (100, 20));
+//! assert_eq!(strip.get_pixel(0, 0).0, [254, 237, 222]);
+//!
+//! let sheet = contact_sheet(8);
+//! // sheet.save("colorbrewer.png").unwrap();
+//! ```
+
+use crate::{Error, Palette};
+use image::{Rgb, RgbImage};
+use rgb::RGB;
+
+/// Background of the contact sheet.
+const BACKGROUND: Rgb<u8> = Rgb([255, 255, 255]);
+
+fn pixel(color: RGB<u8>) -> Rgb<u8> {
+    Rgb([color.r, color.g, color.b])
+}
+
+/// Fill a rectangle of `image`, clipped to its bounds.
+fn fill(image: &mut RgbImage, x: u32, y: u32, width: u32, height: u32, color: Rgb<u8>) {
+    let x_end = (x + width).min(image.width());
+    let y_end = (y + height).min(image.height());
+    for py in y..y_end {
+        for px in x..x_end {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// Horizontal strip of `colors`, each swatch being `swatch_width` by `height` pixels.
+pub fn strip(colors: &[RGB<u8>], swatch_width: u32, height: u32) -> RgbImage {
+    RgbImage::from_fn(swatch_width * colors.len() as u32, height, |x, _| {
+        pixel(colors[(x / swatch_width) as usize])
+    })
+}
+
+/// Horizontal strip of the ramp of `palette` with `nb_value` colors, made of square
+/// swatches of `swatch_size` pixels.
+///
+/// Return an error if the palette doesn't define this number of colors.
+pub fn palette_strip(palette: Palette, nb_value: u32, swatch_size: u32) -> Result<RgbImage, Error> {
+    Ok(strip(
+        palette.try_colors(nb_value)?,
+        swatch_size,
+        swatch_size,
+    ))
+}
+
+/// Grid of `colors` with `columns` square swatches of `swatch_size` pixels per row,
+/// filled row by row. The cells after the last color are white.
+pub fn grid(colors: &[RGB<u8>], columns: u32, swatch_size: u32) -> RgbImage {
+    let columns = columns.max(1);
+    let rows = (colors.len() as u32).div_ceil(columns);
+    RgbImage::from_fn(columns * swatch_size, rows * swatch_size, |x, y| {
+        let index = (y / swatch_size) * columns + x / swatch_size;
+        colors
+            .get(index as usize)
+            .map_or(BACKGROUND, |&color| pixel(color))
+    })
+}
+
+/// Overview of all the palettes at all their numbers of colors, like the one of
+/// colorbrewer2.org: one row per palette (grouped by scheme type) and one column
+/// per number of colors, from 3 to 12, each ramp being drawn as a strip of square
+/// swatches of `swatch_size` pixels.
+pub fn contact_sheet(swatch_size: u32) -> RgbImage {
+    let max_classes = Palette::iter().map(|p| p.max_classes()).max().unwrap();
+    let min_classes = Palette::iter().map(|p| p.min_classes()).min().unwrap();
+    let gap = (swatch_size / 2).max(1);
+    let cell_width = |nb_value: u32| nb_value * swatch_size + gap;
+    let columns_width: u32 = (min_classes..=max_classes).map(cell_width).sum();
+
+    let mut palettes: Vec<Palette> = Palette::iter().collect();
+    palettes.sort_by_key(|p| p.scheme_type() as u8);
+    let nb_group = 1 + palettes
+        .windows(2)
+        .filter(|w| w[0].scheme_type() != w[1].scheme_type())
+        .count() as u32;
+    let height = palettes.len() as u32 * (swatch_size + gap) + (nb_group - 1) * swatch_size + gap;
+    let mut image = RgbImage::from_pixel(columns_width + gap, height, BACKGROUND);
+
+    let mut y = gap;
+    for (i, &palette) in palettes.iter().enumerate() {
+        if i > 0 && palettes[i - 1].scheme_type() != palette.scheme_type() {
+            y += swatch_size;
+        }
+        let mut x = gap;
+        for nb_value in min_classes..=max_classes {
+            if let Some(colors) = palette.colors(nb_value) {
+                for (j, &color) in colors.iter().enumerate() {
+                    let swatch_x = x + j as u32 * swatch_size;
+                    fill(
+                        &mut image,
+                        swatch_x,
+                        y,
+                        swatch_size,
+                        swatch_size,
+                        pixel(color),
+                    );
+                }
+            }
+            x += cell_width(nb_value);
+        }
+        y += swatch_size + gap;
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_and_grid() {
+        let colors = Palette::Set1.colors(5).unwrap();
+        let image = strip(colors, 3, 2);
+        assert_eq!(image.dimensions(), (15, 2));
+        assert_eq!(*image.get_pixel(14, 1), pixel(colors[4]));
+
+        let image = grid(colors, 2, 4);
+        assert_eq!(image.dimensions(), (8, 12));
+        assert_eq!(*image.get_pixel(7, 4), pixel(colors[3]));
+        assert_eq!(*image.get_pixel(0, 11), pixel(colors[4]));
+        assert_eq!(*image.get_pixel(7, 11), BACKGROUND);
+        assert!(palette_strip(Palette::Set1, 12, 4).is_err());
+    }
+
+    #[test]
+    fn test_contact_sheet() {
+        let image = contact_sheet(2);
+        // 10 columns (3 to 12 colors) and 35 rows in 3 groups.
+        assert_eq!(image.width(), (3..=12).map(|n| n * 2 + 1).sum::<u32>() + 1);
+        assert_eq!(image.height(), 35 * 3 + 2 * 2 + 1);
+        let first = Palette::iter()
+            .find(|p| p.scheme_type() == crate::SchemeType::Sequential)
+            .unwrap();
+        assert_eq!(*image.get_pixel(1, 1), pixel(first.colors(3).unwrap()[0]));
+    }
+}
@@ -0,0 +1,174 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! `Palette` is serialized as its ColorBrewer name and a [`Ramp`] as a list of
+//! `#rrggbb` hexadecimal strings. A ramp can be deserialized from a list of
+//! hexadecimal strings or of `[r, g, b]` arrays.
+//!
+//! The [`hex`] and [`array`](mod@array) modules can be used with `#[serde(with = "...")]`
+//! to choose how a list of colors is serialized:
+//!
+//! ```rust
+//! use colorbrewer::Palette;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Style {
+//!     palette: Palette,
+//!     #[serde(with = "colorbrewer::serde_colors::array")]
+//!     colors: Vec<rgb::RGB<u8>>,
+//! }
+//!
+//! let style = Style {
+//!     palette: Palette::Greens,
+//!     colors: Palette::Greens.colors(3).unwrap().to_vec(),
+//! };
+//! assert_eq!(
+//!     serde_json::to_string(&style).unwrap(),
+//!     r#"{"palette":"Greens","colors":[[229,245,224],[161,217,155],[49,163,84]]}"#
+//! );
+//! ```
+
+use crate::format::{parse_hex, to_hex};
+use crate::{Palette, Ramp};
+use rgb::RGB;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+impl Serialize for Palette {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Palette {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Ramp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        hex::serialize(self.colors(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ramp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let colors = Vec::<AnyColor>::deserialize(deserializer)?;
+        Ok(Ramp::new(colors.into_iter().map(|c| c.0).collect()))
+    }
+}
+
+/// Color deserialized either from a hexadecimal string or from a `[r, g, b]` array.
+struct AnyColor(RGB<u8>);
+
+impl<'de> Deserialize<'de> for AnyColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Hex(String),
+            Array([u8; 3]),
+        }
+        match Repr::deserialize(deserializer)? {
+            Repr::Hex(s) => parse_hex(&s).map(AnyColor).map_err(de::Error::custom),
+            Repr::Array([r, g, b]) => Ok(AnyColor(RGB { r, g, b })),
+        }
+    }
+}
+
+/// (De)serialize a list of colors as `#rrggbb` hexadecimal strings.
+pub mod hex {
+    use super::*;
+
+    /// Serialize `colors` as a list of hexadecimal strings.
+    pub fn serialize<S: Serializer>(colors: &[RGB<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(colors.len()))?;
+        for &color in colors {
+            seq.serialize_element(&to_hex(color))?;
+        }
+        seq.end()
+    }
+
+    /// Deserialize a list of hexadecimal strings.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RGB<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| parse_hex(s).map_err(de::Error::custom))
+            .collect()
+    }
+}
+
+/// (De)serialize a list of colors as `[r, g, b]` arrays.
+pub mod array {
+    use super::*;
+
+    /// Serialize `colors` as a list of `[r, g, b]` arrays.
+    pub fn serialize<S: Serializer>(colors: &[RGB<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(colors.len()))?;
+        for color in colors {
+            seq.serialize_element(&[color.r, color.g, color.b])?;
+        }
+        seq.end()
+    }
+
+    /// Deserialize a list of `[r, g, b]` arrays.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RGB<u8>>, D::Error> {
+        Ok(Vec::<[u8; 3]>::deserialize(deserializer)?
+            .into_iter()
+            .map(|[r, g, b]| RGB { r, g, b })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Palette, Properties, Ramp, SchemeType};
+
+    #[test]
+    fn test_palette() {
+        assert_eq!(
+            serde_json::to_string(&Palette::RdYlBu).unwrap(),
+            "\"RdYlBu\""
+        );
+        let palette: Palette = serde_json::from_str("\"PRGn\"").unwrap();
+        assert_eq!(palette, Palette::PRGn);
+        let err = serde_json::from_str::<Palette>("\"PRGN\"").unwrap_err();
+        assert!(err.to_string().contains("did you mean \"PRGn\"?"));
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(
+            serde_json::to_string(&SchemeType::Diverging).unwrap(),
+            "\"diverging\""
+        );
+        let properties = Palette::Set2.properties(3).unwrap();
+        let json = serde_json::to_string(&properties).unwrap();
+        assert_eq!(
+            json,
+            r#"{"colorblind_safe":"yes","print_friendly":"yes","photocopy_safe":"no","lcd_friendly":"yes"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Properties>(&json).unwrap(),
+            properties
+        );
+    }
+
+    #[test]
+    fn test_ramp() {
+        let ramp = Palette::Greens.ramp(3).unwrap();
+        let json = serde_json::to_string(&ramp).unwrap();
+        assert_eq!(json, r##"["#e5f5e0","#a1d99b","#31a354"]"##);
+        assert_eq!(serde_json::from_str::<Ramp>(&json).unwrap(), ramp);
+        let mixed = r##"["e5f5e0",[161,217,155],"#31A354"]"##;
+        assert_eq!(serde_json::from_str::<Ramp>(mixed).unwrap(), ramp);
+        assert!(serde_json::from_str::<Ramp>(r##"["#zzzzzz"]"##).is_err());
+    }
+}